@@ -1,7 +1,13 @@
-use std::env;
-use std::path::PathBuf;
-
 fn main() {
+	#[cfg(feature = "bindgen")]
+	generate_bindings();
+}
+
+#[cfg(feature = "bindgen")]
+fn generate_bindings() {
+	use std::env;
+	use std::path::PathBuf;
+
 	let target_windows = std::env::var("CARGO_CFG_TARGET_OS").map_or(false, |t| t.eq_ignore_ascii_case("windows"));
 
 	let twain_wrapper_h = if target_windows { "ext/twain_wrapper_windows.h" } else { "ext/twain_wrapper_unix.h" };
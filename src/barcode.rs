@@ -0,0 +1,178 @@
+use super::{DSError, OpenedDS};
+use super::detection::*;
+use super::extimageinfo::*;
+use super::image::Image;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::fmt;
+
+pub const BARCODE_EXT_IMAGE_INFO: [TwainUConst; 7] = [
+	TWEI_BARCODECOUNT,
+	TWEI_BARCODETEXT,
+	TWEI_BARCODETYPE,
+	TWEI_BARCODEX,
+	TWEI_BARCODEY,
+	TWEI_BARCODEROTATION,
+	TWEI_BARCODECONFIDENCE,
+];
+
+pub const BARCODE_DETECTION: DetectionCapabilities = DetectionCapabilities {
+	enabled: ICAP_BARCODEDETECTIONENABLED,
+	search_priorities: ICAP_BARCODESEARCHPRIORITIES,
+	search_mode: ICAP_BARCODESEARCHMODE,
+	max_retries: ICAP_BARCODEMAXRETRIES,
+	timeout: ICAP_BARCODETIMEOUT,
+	ext_image_info: &BARCODE_EXT_IMAGE_INFO,
+};
+
+pub type BarcodeConfig = DetectionConfig;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Barcode {
+	pub data: Vec<u8>,
+	pub barcode_type: TW_UINT16,
+	pub x: TW_UINT32,
+	pub y: TW_UINT32,
+	pub rotation: TW_UINT16,
+	pub confidence: TW_UINT32,
+}
+
+impl Barcode {
+	pub fn text(&self) -> String {
+		String::from_utf8_lossy(&self.data).into_owned()
+	}
+}
+
+fn nth_u32(info: &ExtImageInfo, id: TwainUConst, n: usize) -> Option<TW_UINT32> {
+	info.get(&(id as TweiId))?.items().get(n)?.as_i64().map(|v| v as TW_UINT32)
+}
+
+pub fn barcodes_from_ext_info(info: &ExtImageInfo) -> Vec<Barcode> {
+	let texts = info.get(&(TWEI_BARCODETEXT as TweiId)).map(|e| e.items()).unwrap_or_default();
+	// The count comes from the source, there can't be more barcodes than items reported for them
+	let reported = [TWEI_BARCODETEXT, TWEI_BARCODETYPE, TWEI_BARCODEX, TWEI_BARCODEY].iter()
+		.filter_map(|id| info.get(&(*id as TweiId)))
+		.map(|e| e.items().len())
+		.max()
+		.unwrap_or(0);
+	let count = nth_u32(info, TWEI_BARCODECOUNT, 0).map_or(texts.len(), |c| c as usize).min(reported);
+
+	(0..count).map(|i| Barcode {
+		data: match texts.get(i) {
+			Some(ExtInfoItem::Data(data)) => data.clone(),
+			Some(item) => item.to_string_lossy().unwrap_or_default().into_bytes(),
+			None => Vec::new(),
+		},
+		barcode_type: nth_u32(info, TWEI_BARCODETYPE, i).unwrap_or(TWON_DONTCARE16) as TW_UINT16,
+		x: nth_u32(info, TWEI_BARCODEX, i).unwrap_or(0),
+		y: nth_u32(info, TWEI_BARCODEY, i).unwrap_or(0),
+		rotation: nth_u32(info, TWEI_BARCODEROTATION, i).unwrap_or(TWBCOR_ROTX) as TW_UINT16,
+		confidence: nth_u32(info, TWEI_BARCODECONFIDENCE, i).unwrap_or(TWON_DONTCARE32),
+	}).collect()
+}
+
+pub fn barcode_type_name(barcode_type: TW_UINT16) -> Option<&'static str> {
+	Some(match barcode_type as TwainUConst {
+		TWBT_3OF9                 => "3OF9",
+		TWBT_2OF5INTERLEAVED      => "2OF5INTERLEAVED",
+		TWBT_2OF5NONINTERLEAVED   => "2OF5NONINTERLEAVED",
+		TWBT_CODE93               => "CODE93",
+		TWBT_CODE128              => "CODE128",
+		TWBT_UCC128               => "UCC128",
+		TWBT_CODABAR              => "CODABAR",
+		TWBT_UPCA                 => "UPCA",
+		TWBT_UPCE                 => "UPCE",
+		TWBT_EAN8                 => "EAN8",
+		TWBT_EAN13                => "EAN13",
+		TWBT_POSTNET              => "POSTNET",
+		TWBT_PDF417               => "PDF417",
+		TWBT_2OF5INDUSTRIAL       => "2OF5INDUSTRIAL",
+		TWBT_2OF5MATRIX           => "2OF5MATRIX",
+		TWBT_2OF5DATALOGIC        => "2OF5DATALOGIC",
+		TWBT_2OF5IATA             => "2OF5IATA",
+		TWBT_3OF9FULLASCII        => "3OF9FULLASCII",
+		TWBT_CODABARWITHSTARTSTOP => "CODABARWITHSTARTSTOP",
+		TWBT_MAXICODE             => "MAXICODE",
+		TWBT_QRCODE               => "QRCODE",
+		_ => return None,
+	})
+}
+
+impl OpenedDS {
+	pub fn supported_barcode_types(&self) -> Result<Vec<TW_UINT16>, DSError> {
+		let container = self.get_capability(ICAP_SUPPORTEDBARCODETYPES)?;
+		Ok(container.values().iter().filter_map(|v| v.as_u32()).map(|v| v as TW_UINT16).collect())
+	}
+
+	pub fn configure_barcodes(&self, config: &BarcodeConfig) -> Result<(), DSError> {
+		self.configure_detection(&BARCODE_DETECTION, config)
+	}
+}
+
+impl Image {
+	pub fn barcodes(&self) -> Vec<Barcode> {
+		barcodes_from_ext_info(&self.ext_info)
+	}
+}
+
+impl fmt::Display for Barcode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match barcode_type_name(self.barcode_type) {
+			Some(name) => write!(f, "{} ", name)?,
+			None => write!(f, "{} ", self.barcode_type)?,
+		}
+		write!(f, "\"{}\" at ({}, {})", self.text(), self.x, self.y)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::capability::*;
+
+	fn items(values: Vec<CapValue>) -> ExtInfo {
+		ExtInfo::Items(values.into_iter().map(ExtInfoItem::Value).collect())
+	}
+
+	#[test]
+	fn decodes_barcodes_from_ext_info() {
+		let mut info = ExtImageInfo::new();
+		info.insert(TWEI_BARCODECOUNT as TweiId, items(vec![CapValue::UInt32(2)]));
+		info.insert(TWEI_BARCODETEXT as TweiId, ExtInfo::Items(vec![ExtInfoItem::Data(b"INV-0042".to_vec()), ExtInfoItem::Data(b"https://example.com".to_vec())]));
+		info.insert(TWEI_BARCODETYPE as TweiId, items(vec![CapValue::UInt32(TWBT_CODE128), CapValue::UInt32(TWBT_QRCODE)]));
+		info.insert(TWEI_BARCODEX as TweiId, items(vec![CapValue::UInt32(100), CapValue::UInt32(800)]));
+		info.insert(TWEI_BARCODEY as TweiId, items(vec![CapValue::UInt32(50), CapValue::UInt32(900)]));
+		info.insert(TWEI_BARCODEROTATION as TweiId, ExtInfo::NotAvailable);
+
+		let barcodes = barcodes_from_ext_info(&info);
+
+		assert_eq!(2, barcodes.len());
+		assert_eq!("INV-0042", barcodes[0].text());
+		assert_eq!(TWBT_QRCODE as TW_UINT16, barcodes[1].barcode_type);
+		assert_eq!((800, 900), (barcodes[1].x, barcodes[1].y));
+		assert_eq!(TWBCOR_ROTX as TW_UINT16, barcodes[0].rotation);
+		assert_eq!("QRCODE \"https://example.com\" at (800, 900)", barcodes[1].to_string());
+	}
+
+	#[test]
+	fn barcode_count_is_bounded_by_reported_items() {
+		let mut info = ExtImageInfo::new();
+		info.insert(TWEI_BARCODECOUNT as TweiId, items(vec![CapValue::UInt32(0xffffffff)]));
+		info.insert(TWEI_BARCODETEXT as TweiId, ExtInfo::Items(vec![ExtInfoItem::Data(b"INV-0042".to_vec())]));
+		info.insert(TWEI_BARCODEX as TweiId, items(vec![CapValue::UInt32(100), CapValue::UInt32(800)]));
+
+		let barcodes = barcodes_from_ext_info(&info);
+		assert_eq!(2, barcodes.len());
+		assert!(barcodes[1].data.is_empty());
+
+		info.clear();
+		info.insert(TWEI_BARCODECOUNT as TweiId, items(vec![CapValue::UInt32(0xffffffff)]));
+		assert!(barcodes_from_ext_info(&info).is_empty());
+	}
+
+	#[test]
+	fn no_barcodes_without_ext_info() {
+		assert!(barcodes_from_ext_info(&ExtImageInfo::new()).is_empty());
+	}
+}
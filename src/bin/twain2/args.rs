@@ -0,0 +1,192 @@
+use twain2::job::JobControl;
+
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: twain2 [--dsm <path>] [--record <file> | --replay <file>] <command> [arguments]
+
+Commands:
+  info                 Show DSM information
+  list-sources         List available data sources
+  caps <source>        Dump every capability supported by a data source
+  scan <source>        Acquire all pending images from a data source
+
+Options:
+  --dsm <path>         Path to the TWAIN DSM library (default: search the standard locations)
+  --record <file>      Write every DSM call and callback to a session log
+  --replay <file>      Run against a recorded session log instead of a DSM
+  -h, --help           Show this message
+
+Scan options:
+  --resolution <dpi>   Set X and Y resolution
+  --pixel-type <type>  One of bw, gray, rgb
+  --duplex             Enable duplex scanning
+  --feeder             Scan from the document feeder
+  --xfer <mechanism>   One of native (default), memory, file
+  --file-format <fmt>  Format for file transfers: bmp (default), tiff, png, jpeg, pdf
+  --output <path>      Output directory, or a .tif/.tiff file for a multi-page file (default: .)
+  --name <template>    File name template for directory output, {n} or {n:04} is the page number (default: page-{n:04})
+  --job-control <mode> One of none, include, include-stop, exclude, exclude-stop; each job is written to its own job-NNNN directory
+  --show-ui            Show the source's user interface
+  --timeout <seconds>  Time to wait for the source to become ready (default: 60)";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PixelType {
+	BlackWhite,
+	Gray,
+	Rgb,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum XferMech {
+	Native,
+	Memory,
+	File,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanOptions {
+	pub resolution: Option<f64>,
+	pub pixel_type: Option<PixelType>,
+	pub duplex: bool,
+	pub feeder: bool,
+	pub xfer: XferMech,
+	pub file_format: String,
+	pub output: PathBuf,
+	pub name_template: String,
+	pub job_control: Option<JobControl>,
+	pub show_ui: bool,
+	pub timeout: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+	Info,
+	ListSources,
+	Caps { source: String },
+	Scan { source: String, options: ScanOptions },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+	pub dsm_path: Option<PathBuf>,
+	pub record: Option<PathBuf>,
+	pub replay: Option<PathBuf>,
+	pub command: Command,
+}
+
+pub enum ParseResult {
+	Run(Args),
+	Help,
+}
+
+impl Default for ScanOptions {
+	fn default() -> Self {
+		Self {
+			resolution: None,
+			pixel_type: None,
+			duplex: false,
+			feeder: false,
+			xfer: XferMech::Native,
+			file_format: String::from("bmp"),
+			output: PathBuf::from("."),
+			name_template: String::from("page-{n:04}"),
+			job_control: None,
+			show_ui: false,
+			timeout: 60,
+		}
+	}
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: Option<String>) -> Result<T, String> {
+	let value = value.ok_or_else(|| format!("{} requires a value", option))?;
+	value.parse().map_err(|_| format!("Invalid value \"{}\" for {}", value, option))
+}
+
+impl Args {
+	pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<ParseResult, String> {
+		let mut dsm_path = None;
+		let mut record = None;
+		let mut replay = None;
+		let mut positional = Vec::new();
+		let mut scan = ScanOptions::default();
+		let mut scan_option_given = false;
+
+		let mut args = args.into_iter();
+		while let Some(arg) = args.next() {
+			let (name, inline_value) = match arg.split_once('=') {
+				Some((name, value)) if name.starts_with("--") => (name.to_string(), Some(value.to_string())),
+				_ => (arg.clone(), None),
+			};
+			let mut value = || inline_value.clone().or_else(|| args.next());
+
+			match name.as_str() {
+				"-h" | "--help" => return Ok(ParseResult::Help),
+				"--dsm" => dsm_path = Some(PathBuf::from(value().ok_or("--dsm requires a path")?)),
+				"--record" => record = Some(PathBuf::from(value().ok_or("--record requires a path")?)),
+				"--replay" => replay = Some(PathBuf::from(value().ok_or("--replay requires a path")?)),
+				"--resolution" => scan.resolution = Some(parse_value(&name, value())?),
+				"--pixel-type" => scan.pixel_type = Some(match value().as_deref() {
+					Some("bw") => PixelType::BlackWhite,
+					Some("gray") => PixelType::Gray,
+					Some("rgb") => PixelType::Rgb,
+					_ => return Err(String::from("--pixel-type must be one of bw, gray, rgb")),
+				}),
+				"--duplex" => scan.duplex = true,
+				"--feeder" => scan.feeder = true,
+				"--xfer" => scan.xfer = match value().as_deref() {
+					Some("native") => XferMech::Native,
+					Some("memory") => XferMech::Memory,
+					Some("file") => XferMech::File,
+					_ => return Err(String::from("--xfer must be one of native, memory, file")),
+				},
+				"--file-format" => scan.file_format = parse_value(&name, value())?,
+				"--output" => scan.output = PathBuf::from(value().ok_or("--output requires a path")?),
+				"--name" => scan.name_template = parse_value(&name, value())?,
+				"--job-control" => scan.job_control = Some(match value().as_deref() {
+					Some("none") => JobControl::None,
+					Some("include") => JobControl::IncludeSeparatorContinue,
+					Some("include-stop") => JobControl::IncludeSeparatorStop,
+					Some("exclude") => JobControl::ExcludeSeparatorContinue,
+					Some("exclude-stop") => JobControl::ExcludeSeparatorStop,
+					_ => return Err(String::from("--job-control must be one of none, include, include-stop, exclude, exclude-stop")),
+				}),
+				"--show-ui" => scan.show_ui = true,
+				"--timeout" => scan.timeout = parse_value(&name, value())?,
+				s if s.starts_with('-') && s.len() > 1 => return Err(format!("Unknown option \"{}\"", s)),
+				_ => {
+					positional.push(arg);
+					continue;
+				},
+			}
+
+			if !matches!(name.as_str(), "--dsm" | "--record" | "--replay") {
+				scan_option_given = true;
+			}
+		}
+
+		let mut positional = positional.into_iter();
+		let command = match positional.next().as_deref() {
+			Some("info") => Command::Info,
+			Some("list-sources") => Command::ListSources,
+			Some("caps") => Command::Caps { source: positional.next().ok_or("caps requires a source name")? },
+			Some("scan") => Command::Scan { source: positional.next().ok_or("scan requires a source name")?, options: scan },
+			Some(cmd) => return Err(format!("Unknown command \"{}\"", cmd)),
+			None => return Err(String::from("No command given")),
+		};
+
+		if let Some(extra) = positional.next() {
+			return Err(format!("Unexpected argument \"{}\"", extra));
+		}
+
+		if scan_option_given && !matches!(command, Command::Scan { .. }) {
+			return Err(String::from("Scan options are only valid with the scan command"));
+		}
+
+		if replay.is_some() && (record.is_some() || dsm_path.is_some()) {
+			return Err(String::from("--replay can't be combined with --dsm or --record"));
+		}
+
+		Ok(ParseResult::Run(Args { dsm_path, record, replay, command }))
+	}
+}
@@ -0,0 +1,186 @@
+use super::args::*;
+
+use twain2::*;
+use twain2::capability::*;
+use twain2::identity::*;
+use twain2::twain_h::*;
+use twain2::twain_h_ext::*;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub type CommandResult = Result<(), String>;
+
+// Where the DSM entry point came from, for the info command
+struct DSMLocation {
+	path: PathBuf,
+	version: Option<String>,
+	origin: String,
+}
+
+pub fn run(args: &Args) -> CommandResult {
+	let (location, wrapper) = match &args.replay {
+		Some(path) => {
+			let replay = session::Replay::load(path).map_err(|err| format!("Unable to load session \"{}\": {}", path.display(), err))?;
+			(DSMLocation { path: path.clone(), version: None, origin: String::from("session replay") }, replay.wrapper())
+		},
+		None => load_dsm(args.dsm_path.as_deref())?,
+	};
+
+	if let Some(path) = &args.record {
+		let recorder = session::SessionRecorder::create(path).map_err(|err| format!("Unable to create session \"{}\": {}", path.display(), err))?;
+		wrapper.add_interceptor(recorder);
+	}
+
+	let dsm = OpenedDSM::new(Arc::new(wrapper), app_identity())
+		.map_err(|res| format!("Unable to open DSM: {}", res))?;
+
+	match &args.command {
+		Command::Info => info(&location, &dsm),
+		Command::ListSources => list_sources(&dsm),
+		Command::Caps { source } => caps(&dsm, source),
+		Command::Scan { source, options } => super::scan::scan(&dsm, source, options),
+	}
+}
+
+fn app_identity() -> TW_IDENTITY {
+	TW_IDENTITY {
+		Id: 0,
+		Version: TW_VERSION {
+			MajorNum: env!("CARGO_PKG_VERSION_MAJOR").parse::<u16>().unwrap() as TW_UINT16,
+			MinorNum: env!("CARGO_PKG_VERSION_MINOR").parse::<u16>().unwrap() as TW_UINT16,
+			Language: TWLG_ENGLISH_USA as TW_UINT16,
+			Country: TWCY_USA as TW_UINT16,
+			Info: tw_str32(env!("CARGO_PKG_VERSION")),
+		},
+		ProtocolMajor: TWON_PROTOCOLMAJOR as TW_UINT16,
+		ProtocolMinor: TWON_PROTOCOLMINOR as TW_UINT16,
+		SupportedGroups: DG_IMAGE | DG_CONTROL | DF_APP2,
+		Manufacturer: tw_str32("Rust TWAIN Library"),
+		ProductFamily: tw_str32("Command Line"),
+		ProductName: tw_str32("twain2"),
+	}
+}
+
+fn load_dsm(path: Option<&Path>) -> Result<(DSMLocation, DSMEntryWrapper), String> {
+	if let Some(path) = path {
+		let wrapper = DSMEntryWrapper::from_path(path)
+			.map_err(|err| format!("Unable to load DSM library \"{}\": {}", path.display(), err))?;
+		return Ok((explicit_location(path), wrapper));
+	}
+
+	discover_dsm()
+}
+
+#[cfg(target_os = "linux")]
+fn explicit_location(path: &Path) -> DSMLocation {
+	match discovery::explicit_candidate(path) {
+		Some(candidate) => DSMLocation::from(candidate),
+		None => DSMLocation { path: path.to_path_buf(), version: None, origin: discovery::CandidateOrigin::Explicit.to_string() },
+	}
+}
+
+#[cfg(not(target_os = "linux"))]
+fn explicit_location(path: &Path) -> DSMLocation {
+	DSMLocation { path: path.to_path_buf(), version: None, origin: String::from("explicit path") }
+}
+
+#[cfg(target_os = "linux")]
+fn discover_dsm() -> Result<(DSMLocation, DSMEntryWrapper), String> {
+	discovery::load_best_dsm()
+		.map(|(candidate, wrapper)| (DSMLocation::from(candidate), wrapper))
+		.ok_or_else(|| format!("No TWAIN DSM found, use --dsm or set {}", discovery::DSM_PATH_ENV))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn discover_dsm() -> Result<(DSMLocation, DSMEntryWrapper), String> {
+	Err(String::from("--dsm is required on this platform"))
+}
+
+#[cfg(target_os = "linux")]
+impl From<discovery::DSMCandidate> for DSMLocation {
+	fn from(candidate: discovery::DSMCandidate) -> Self {
+		Self { version: candidate.version_string(), origin: candidate.origin.to_string(), path: candidate.path }
+	}
+}
+
+pub fn find_source(dsm: &OpenedDSM, name: &str) -> Result<DataSourceInfo, String> {
+	let sources = dsm.get_data_sources().map_err(|res| format!("Unable to list sources: {}", res))?;
+	sources.iter()
+		.find(|ds| ds.product_name == name)
+		.or_else(|| sources.iter().find(|ds| ds.id.to_string() == name))
+		.or_else(|| sources.iter().find(|ds| ds.product_name.to_lowercase().contains(&name.to_lowercase())))
+		.cloned()
+		.ok_or_else(|| format!("No source matching \"{}\"", name))
+}
+
+fn info(location: &DSMLocation, dsm: &OpenedDSM) -> CommandResult {
+	let app = DataSourceInfo::from_identity(*dsm.app_identity.read());
+
+	println!("DSM library:     {}", location.path.display());
+	println!("DSM version:     {}", location.version.as_deref().unwrap_or("unknown"));
+	println!("Found through:   {}", location.origin);
+	println!("Application ID:  {}", app.id);
+	println!("TWAIN 2 DSM:     {}", if app.supported_groups.dsm2() { "yes" } else { "no" });
+	println!("Entry points:    {}", if dsm.entry_points.is_some() { "available" } else { "unavailable" });
+
+	match dsm.get_data_sources() {
+		Ok(sources) => println!("Data sources:    {}", sources.len()),
+		Err(res) => println!("Data sources:    error: {}", res),
+	}
+
+	match dsm.default_source() {
+		Ok(ds) => println!("Default source:  {}", ds),
+		Err(res) => println!("Default source:  error: {}", res),
+	}
+
+	Ok(())
+}
+
+fn list_sources(dsm: &OpenedDSM) -> CommandResult {
+	let sources = dsm.get_data_sources().map_err(|res| format!("Unable to list sources: {}", res))?;
+
+	for ds in sources {
+		println!("{}", ds.product_name);
+		println!("  ID:           {}", ds.id);
+		println!("  Manufacturer: {}", ds.manufacturer);
+		println!("  Family:       {}", ds.product_family);
+		println!("  Version:      {}", ds.version);
+		println!("  Protocol:     {}.{}", ds.protocol_major, ds.protocol_minor);
+		println!("  Groups:       {}", ds.supported_groups);
+	}
+
+	Ok(())
+}
+
+fn caps(dsm: &Arc<OpenedDSM>, source: &str) -> CommandResult {
+	let ds = find_source(dsm, source)?;
+	let ds = dsm.open_data_source(ds.identity).map_err(|res| format!("Unable to open \"{}\": {}", ds.product_name, res))?;
+
+	let caps = ds.supported_capabilities().map_err(|err| format!("Unable to get CAP_SUPPORTEDCAPS: {}", err))?;
+
+	for cap in caps {
+		let cap_u = cap as TwainUConst;
+
+		match ds.query_capability_support(cap_u) {
+			Ok(support) => println!("{} (0x{:04x}) [{}]", cap_label(cap), cap, support),
+			Err(_) => println!("{} (0x{:04x})", cap_label(cap), cap),
+		}
+
+		let show = |label: &str, result: Result<CapContainer, DSError>| {
+			match result {
+				Ok(container) => {
+					let item_type = container.item_type().map_or("", item_type_name);
+					println!("  {:<8} {} {}", label, item_type, container);
+				},
+				Err(err) => println!("  {:<8} error: {}", label, err),
+			}
+		};
+
+		show("allowed", ds.get_capability(cap_u));
+		show("current", ds.get_capability_current(cap_u));
+		show("default", ds.get_capability_default(cap_u));
+	}
+
+	Ok(())
+}
@@ -0,0 +1,26 @@
+mod args;
+mod commands;
+mod scan;
+
+use args::*;
+
+use std::process;
+
+fn main() {
+	let args = match Args::parse(std::env::args().skip(1)) {
+		Ok(ParseResult::Run(args)) => args,
+		Ok(ParseResult::Help) => {
+			println!("{}", USAGE);
+			return;
+		},
+		Err(msg) => {
+			eprintln!("twain2: {}\n\n{}", msg, USAGE);
+			process::exit(2);
+		},
+	};
+
+	if let Err(msg) = commands::run(&args) {
+		eprintln!("twain2: {}", msg);
+		process::exit(1);
+	}
+}
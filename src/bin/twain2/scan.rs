@@ -0,0 +1,271 @@
+use super::args::*;
+use super::commands::*;
+
+use twain2::*;
+use twain2::capability::*;
+use twain2::feeder::*;
+use twain2::image::*;
+use twain2::job::JobControl;
+use twain2::twain_h::*;
+use twain2::twain_h_ext::*;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::Arc;
+use std::time::Duration;
+
+enum Output {
+	Directory { path: PathBuf, template: String },
+	MultiPage { path: PathBuf, images: Vec<Image> },
+}
+
+fn expand_template(template: &str, n: usize) -> String {
+	let mut result = String::new();
+	let mut rest = template;
+
+	while let Some(start) = rest.find("{n") {
+		result.push_str(&rest[..start]);
+		let after = &rest[start..];
+		match after.find('}') {
+			Some(end) => {
+				let width = after[2..end].strip_prefix(":0").and_then(|w| w.parse::<usize>().ok()).unwrap_or(0);
+				result.push_str(&format!("{:0width$}", n, width = width));
+				rest = &after[end + 1..];
+			},
+			None => {
+				result.push_str(after);
+				rest = "";
+			},
+		}
+	}
+
+	result.push_str(rest);
+	result
+}
+
+fn job_dir(job: usize) -> String {
+	format!("job-{:04}", job)
+}
+
+fn file_format(name: &str) -> Result<(TwainUConst, &'static str), String> {
+	match name {
+		"bmp"          => Ok((TWFF_BMP, "bmp")),
+		"tiff" | "tif" => Ok((TWFF_TIFF, "tif")),
+		"png"          => Ok((TWFF_PNG, "png")),
+		"jpeg" | "jpg" => Ok((TWFF_JFIF, "jpg")),
+		"pdf"          => Ok((TWFF_PDF, "pdf")),
+		_ => Err(format!("Unsupported file format \"{}\"", name)),
+	}
+}
+
+impl Output {
+	fn new(options: &ScanOptions) -> Result<Self, String> {
+		let path = options.output.clone();
+		let jobs = options.job_control.is_some_and(|mode| mode != JobControl::None);
+		let is_tiff = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("tif") || ext.eq_ignore_ascii_case("tiff"));
+
+		if is_tiff {
+			if options.xfer == XferMech::File {
+				return Err(String::from("Multi-page output is not supported with file transfers"));
+			}
+			if jobs {
+				return Err(String::from("Multi-page output is not supported with job control"));
+			}
+			Ok(Self::MultiPage { path, images: Vec::new() })
+		} else {
+			let path = if jobs { path.join(job_dir(1)) } else { path };
+			Ok(Self::Directory { path, template: options.name_template.clone() })
+		}
+	}
+
+	fn start_job(&mut self, base: &Path, job: usize) {
+		if let Self::Directory { path, .. } = self {
+			*path = base.join(job_dir(job));
+		}
+	}
+
+	// Directories are created on first use so a finished batch doesn't leave an empty job directory behind
+	fn ensure_dir(&self) -> Result<(), String> {
+		if let Self::Directory { path, .. } = self {
+			fs::create_dir_all(path).map_err(|err| format!("Unable to create \"{}\": {}", path.display(), err))?;
+		}
+		Ok(())
+	}
+
+	fn file_path(&self, n: usize, extension: &str) -> PathBuf {
+		match self {
+			Self::Directory { path, template } => path.join(format!("{}.{}", expand_template(template, n), extension)),
+			Self::MultiPage { path, .. } => path.clone(),
+		}
+	}
+
+	fn add(&mut self, n: usize, image: Image) -> Result<(), String> {
+		let file_path = self.file_path(n, "bmp");
+		match self {
+			Self::Directory { .. } => {
+				self.ensure_dir()?;
+				write_file(&file_path, &image.to_bmp())?;
+				println!("{}", file_path.display());
+			},
+			Self::MultiPage { images, .. } => images.push(image),
+		}
+		Ok(())
+	}
+
+	fn finish(self) -> Result<(), String> {
+		if let Self::MultiPage { path, images } = self {
+			// A batch that fails before its first page leaves nothing to write
+			if images.is_empty() {
+				return Ok(());
+			}
+
+			let mut tiff = Vec::new();
+			write_multipage_tiff(&mut tiff, &images).map_err(|err| err.to_string())?;
+			write_file(&path, &tiff)?;
+			println!("{}", path.display());
+		}
+		Ok(())
+	}
+}
+
+fn write_file(path: &Path, data: &[u8]) -> Result<(), String> {
+	fs::write(path, data).map_err(|err| format!("Unable to write \"{}\": {}", path.display(), err))
+}
+
+fn set_cap(ds: &OpenedDS, cap: TwainUConst, value: CapValue) -> Result<(), String> {
+	ds.set_capability_value(cap, value).map_err(|err| format!("Unable to set {}: {}", cap_label(cap as TW_UINT16), err))
+}
+
+fn apply_settings(ds: &OpenedDS, options: &ScanOptions) -> Result<(), String> {
+	let mech = match options.xfer {
+		XferMech::Native => TWSX_NATIVE,
+		XferMech::Memory => TWSX_MEMORY,
+		XferMech::File   => TWSX_FILE,
+	};
+	set_cap(ds, ICAP_XFERMECH, CapValue::UInt16(mech as TW_UINT16))?;
+
+	if let Some(pixel_type) = options.pixel_type {
+		let pixel_type = match pixel_type {
+			PixelType::BlackWhite => TWPT_BW,
+			PixelType::Gray       => TWPT_GRAY,
+			PixelType::Rgb        => TWPT_RGB,
+		};
+		set_cap(ds, ICAP_PIXELTYPE, CapValue::UInt16(pixel_type as TW_UINT16))?;
+	}
+
+	if let Some(resolution) = options.resolution {
+		set_cap(ds, ICAP_XRESOLUTION, CapValue::Fix32(resolution))?;
+		set_cap(ds, ICAP_YRESOLUTION, CapValue::Fix32(resolution))?;
+	}
+
+	if options.feeder || options.duplex {
+		let config = FeederConfig {
+			feeder_enabled: options.feeder,
+			duplex_enabled: options.duplex,
+			..Default::default()
+		};
+		let settings = ds.apply_feeder_config(&config).map_err(|err| match err {
+			FeederError::NoPaper => String::from("The document feeder is empty"),
+			err => format!("Unable to configure the document feeder: {}", err),
+		})?;
+		eprintln!("Feeder {}, duplex {}", if settings.feeder_enabled { "enabled" } else { "disabled" }, if settings.duplex_enabled { "enabled" } else { "disabled" });
+	}
+
+	if let Some(mode) = options.job_control {
+		set_cap(ds, CAP_JOBCONTROL, CapValue::UInt16(mode.to_twjc()))?;
+	}
+
+	Ok(())
+}
+
+pub fn scan(dsm: &Arc<OpenedDSM>, source: &str, options: &ScanOptions) -> CommandResult {
+	let info = find_source(dsm, source)?;
+	let ds = dsm.open_data_source(info.identity).map_err(|res| format!("Unable to open \"{}\": {}", info.product_name, res))?;
+
+	apply_settings(&ds, options)?;
+
+	let (format, extension) = file_format(&options.file_format)?;
+	let mut output = Output::new(options)?;
+
+	let ui = TW_USERINTERFACE {
+		ShowUI: options.show_ui as TW_BOOL,
+		ModalUI: 0,
+		hParent: ptr::null_mut(),
+	};
+	ds.enable(ui).map_err(|err| format!("Unable to enable \"{}\": {}", info.product_name, err))?;
+
+	if ds.wait_while_state(DSState::SourceEnabled, Duration::from_secs(options.timeout)) != DSState::TransferReady {
+		return Err(format!("\"{}\" did not become ready for transfer", info.product_name));
+	}
+
+	let mut pages = 0;
+	let result = acquire_batch(&ds, &info.product_name, options, &mut output, format, extension, &mut pages);
+
+	// Pages acquired before an error are still written
+	let finished = output.finish();
+	eprintln!("{} page(s) acquired from \"{}\"", pages, info.product_name);
+
+	match (result, finished) {
+		(Err(err), Err(finish_err)) => Err(format!("{}, {}", err, finish_err)),
+		(result, finished) => result.and(finished),
+	}
+}
+
+fn acquire_batch(ds: &OpenedDS, product_name: &str, options: &ScanOptions, output: &mut Output, format: TwainUConst, extension: &str, pages: &mut i32) -> CommandResult {
+	let jobs = options.job_control.is_some_and(|mode| mode != JobControl::None);
+	let stops_after_job = options.job_control.is_some_and(|mode| mode.stops_after_job());
+	let (mut job, mut job_pages) = (1, 0);
+
+	loop {
+		while ds.get_state() == DSState::TransferReady {
+			let n = job_pages + 1;
+			let acquired = match options.xfer {
+				XferMech::Native => ds.acquire_image().map_err(|err| err.to_string())?.map(|image| output.add(n, image)).transpose()?.is_some(),
+				XferMech::Memory => ds.acquire_memory_image().map_err(|err| err.to_string())?.map(|image| output.add(n, image)).transpose()?.is_some(),
+				XferMech::File => {
+					output.ensure_dir()?;
+					let path = output.file_path(n, extension);
+					ds.setup_file_transfer(&path, format).map_err(|err| format!("Unable to set up file transfer: {}", err))?;
+					let done = ds.acquire_file_image().map_err(|err| err.to_string())?;
+					if done {
+						println!("{}", path.display());
+					}
+					done
+				},
+			};
+
+			if acquired {
+				*pages += 1;
+				job_pages += 1;
+				match ds.last_transfer() {
+					Some(outcome) if outcome.is_count_known() => eprintln!("Page {} of {}", pages, *pages + outcome.count),
+					_ => eprintln!("Page {}", pages),
+				}
+			}
+
+			// The page is kept when the source fails to end its transfer, but the rest of the batch is lost
+			if ds.get_state() == DSState::Transferring {
+				ds.end_faulted_transfer().map_err(|err| format!("Unable to recover \"{}\" from a failed transfer: {}", product_name, err))?;
+				return Err(format!("\"{}\" failed to end a transfer after {} page(s)", product_name, pages));
+			}
+
+			if jobs && ds.end_of_job() != TWEJ_NONE {
+				eprintln!("Job {} finished with {} page(s)", job, job_pages);
+				job += 1;
+				job_pages = 0;
+				output.start_job(&options.output, job);
+			}
+		}
+
+		// Stop modes pause the source after each job until the next one is started on the device
+		if !stops_after_job || ds.end_of_job() == TWEJ_NONE {
+			break;
+		}
+		if ds.wait_while_state(DSState::SourceEnabled, Duration::from_secs(options.timeout)) != DSState::TransferReady {
+			break;
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,139 @@
+use super::{DSError, OpenedDS};
+use super::capability::*;
+use super::image::Image;
+use super::twain_h::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlankPageMode {
+	Disabled,
+	Auto,
+	// Images smaller than this many bytes are discarded by the source
+	ByteSize(TW_INT32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BlankDetector {
+	// Images with at most this fraction of dark pixels are blank
+	pub threshold: f64,
+	// Fraction of the width and height ignored on each edge, where scan borders and shadows show up
+	pub margin: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BlankDecision {
+	pub blank: bool,
+	pub ink_coverage: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BlankPageConfig {
+	pub mode: BlankPageMode,
+	pub host_fallback: Option<BlankDetector>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlankPageHandling {
+	Device,
+	Host(BlankDetector),
+	Unavailable,
+}
+
+impl BlankPageMode {
+	pub fn from_value(value: TW_INT32) -> Self {
+		match value {
+			TWBP_DISABLE => Self::Disabled,
+			TWBP_AUTO => Self::Auto,
+			size => Self::ByteSize(size),
+		}
+	}
+
+	pub fn to_value(&self) -> TW_INT32 {
+		match self {
+			Self::Disabled => TWBP_DISABLE,
+			Self::Auto => TWBP_AUTO,
+			Self::ByteSize(size) => *size,
+		}
+	}
+}
+
+impl Default for BlankDetector {
+	fn default() -> Self {
+		Self { threshold: 0.005, margin: 0.05 }
+	}
+}
+
+impl BlankDetector {
+	pub fn detect(&self, image: &Image) -> BlankDecision {
+		let ink_coverage = image.ink_coverage_inside(self.margin);
+		BlankDecision { blank: ink_coverage <= self.threshold, ink_coverage }
+	}
+}
+
+impl OpenedDS {
+	pub fn blank_page_mode(&self) -> Result<BlankPageMode, DSError> {
+		let container = self.get_capability_current(ICAP_AUTODISCARDBLANKPAGES)?;
+		let value = container.current().and_then(|v| v.as_i64()).ok_or(DSError::BadContainer(container.con_type()))?;
+		Ok(BlankPageMode::from_value(value as TW_INT32))
+	}
+
+	pub fn set_blank_page_mode(&self, mode: BlankPageMode) -> Result<(), DSError> {
+		self.set_capability_value(ICAP_AUTODISCARDBLANKPAGES, CapValue::Int32(mode.to_value()))
+	}
+
+	pub fn configure_blank_pages(&self, config: &BlankPageConfig) -> Result<BlankPageHandling, DSError> {
+		let device_support = self.query_capability_support(ICAP_AUTODISCARDBLANKPAGES).is_ok_and(|support| support.set());
+
+		let handling = if device_support {
+			self.set_blank_page_mode(config.mode)?;
+			*self.blank_detector.write() = None;
+			BlankPageHandling::Device
+		} else if let (Some(detector), true) = (config.host_fallback, config.mode != BlankPageMode::Disabled) {
+			*self.blank_detector.write() = Some(detector);
+			BlankPageHandling::Host(detector)
+		} else {
+			*self.blank_detector.write() = None;
+			BlankPageHandling::Unavailable
+		};
+
+		log::debug!("Blank page handling on \"{}\": {:?}", self.name, handling);
+		Ok(handling)
+	}
+
+	pub fn detect_blank(&self, image: &Image) -> Option<BlankDecision> {
+		self.blank_detector.read().map(|detector| detector.detect(image))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::image::PixelFormat;
+
+	fn white_page() -> Image {
+		let mut image = Image::new(100, 100, PixelFormat::Gray8);
+		image.data.iter_mut().for_each(|p| *p = 255);
+		image
+	}
+
+	#[test]
+	fn blank_page_mode_values() {
+		assert_eq!(BlankPageMode::Disabled, BlankPageMode::from_value(TWBP_DISABLE));
+		assert_eq!(BlankPageMode::Auto, BlankPageMode::from_value(TWBP_AUTO));
+		assert_eq!(BlankPageMode::ByteSize(1024), BlankPageMode::from_value(1024));
+		assert_eq!(TWBP_AUTO, BlankPageMode::Auto.to_value());
+	}
+
+	#[test]
+	fn detector_ignores_margins() {
+		let detector = BlankDetector { threshold: 0.01, margin: 0.05 };
+
+		let mut page = white_page();
+		page.data[..100 * 3].iter_mut().for_each(|p| *p = 0);
+		assert!(detector.detect(&page).blank);
+
+		page.data[50 * 100..51 * 100].iter_mut().for_each(|p| *p = 0);
+		let decision = detector.detect(&page);
+		assert!(!decision.blank);
+		assert!(decision.ink_coverage > 0.01);
+	}
+}
@@ -0,0 +1,807 @@
+use super::{DSError, DSState, OpenedDS};
+use super::data::PointerFromHandle;
+use super::entrypoint::EntryPoints;
+use super::response::*;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::fmt;
+use std::mem;
+use std::ptr;
+
+const ONEVALUE_HEADER_SIZE: usize = 2;
+const ARRAY_HEADER_SIZE: usize = 6;
+const ENUMERATION_HEADER_SIZE: usize = 14;
+const RANGE_SIZE: usize = 22;
+
+// Every container starts with at least an item type and a TW_UINT32
+pub const CONTAINER_LEN_HEADER_SIZE: usize = 6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CapValue {
+	Int8(TW_INT8),
+	Int16(TW_INT16),
+	Int32(TW_INT32),
+	UInt8(TW_UINT8),
+	UInt16(TW_UINT16),
+	UInt32(TW_UINT32),
+	Bool(bool),
+	Fix32(f64),
+	Frame { left: f64, top: f64, right: f64, bottom: f64 },
+	Str32(String),
+	Str64(String),
+	Str128(String),
+	Str255(String),
+	// Only the value of the handle, the memory belongs to the source and is never reached through a CapValue
+	Handle(TW_UINTPTR),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CapContainer {
+	OneValue(CapValue),
+	Array(Vec<CapValue>),
+	Enumeration { items: Vec<CapValue>, current_index: usize, default_index: usize },
+	Range { min: CapValue, max: CapValue, step: CapValue, default: CapValue, current: CapValue },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct QuerySupport(pub TW_INT32);
+
+pub fn fix32_to_f64(fix: TW_FIX32) -> f64 {
+	fix.Whole as f64 + fix.Frac as f64 / 65536.0
+}
+
+pub fn f64_to_fix32(value: f64) -> TW_FIX32 {
+	let value = (value * 65536.0).round() as i32;
+	TW_FIX32 { Whole: (value >> 16) as TW_INT16, Frac: (value & 0xffff) as TW_UINT16 }
+}
+
+pub fn item_size(item_type: TW_UINT16) -> Option<usize> {
+	match item_type as TwainUConst {
+		TWTY_INT8   | TWTY_UINT8  => Some(1),
+		TWTY_INT16  | TWTY_UINT16 | TWTY_BOOL => Some(2),
+		TWTY_INT32  | TWTY_UINT32 | TWTY_FIX32 => Some(4),
+		TWTY_FRAME  => Some(16),
+		TWTY_STR32  => Some(mem::size_of::<TW_STR32>()),
+		TWTY_STR64  => Some(mem::size_of::<TW_STR64>()),
+		TWTY_STR128 => Some(mem::size_of::<TW_STR128>()),
+		TWTY_STR255 => Some(mem::size_of::<TW_STR255>()),
+		TWTY_HANDLE => Some(mem::size_of::<TW_HANDLE>()),
+		_ => None,
+	}
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+	Some(u16::from_ne_bytes(bytes.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+	Some(u32::from_ne_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn read_fix32(bytes: &[u8], offset: usize) -> Option<f64> {
+	let whole = read_u16(bytes, offset)? as TW_INT16;
+	let frac = read_u16(bytes, offset + 2)?;
+	Some(fix32_to_f64(TW_FIX32 { Whole: whole, Frac: frac }))
+}
+
+fn write_fix32(bytes: &mut Vec<u8>, value: f64) {
+	let fix = f64_to_fix32(value);
+	let (whole, frac) = (fix.Whole, fix.Frac);
+	bytes.extend_from_slice(&whole.to_ne_bytes());
+	bytes.extend_from_slice(&frac.to_ne_bytes());
+}
+
+fn read_str(bytes: &[u8]) -> String {
+	let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+	String::from_utf8_lossy(&bytes[..len]).into_owned()
+}
+
+fn write_str(bytes: &mut Vec<u8>, s: &str, size: usize) {
+	let len = std::cmp::min(size - 1, s.len());
+	bytes.extend_from_slice(&s.as_bytes()[..len]);
+	bytes.resize(bytes.len() + size - len, 0);
+}
+
+impl CapValue {
+	pub fn from_bytes(item_type: TW_UINT16, bytes: &[u8]) -> Option<Self> {
+		let size = item_size(item_type)?;
+		let bytes = bytes.get(..size)?;
+
+		Some(match item_type as TwainUConst {
+			TWTY_INT8   => Self::Int8(bytes[0] as TW_INT8),
+			TWTY_INT16  => Self::Int16(read_u16(bytes, 0)? as TW_INT16),
+			TWTY_INT32  => Self::Int32(read_u32(bytes, 0)? as TW_INT32),
+			TWTY_UINT8  => Self::UInt8(bytes[0]),
+			TWTY_UINT16 => Self::UInt16(read_u16(bytes, 0)?),
+			TWTY_UINT32 => Self::UInt32(read_u32(bytes, 0)?),
+			TWTY_BOOL   => Self::Bool(read_u16(bytes, 0)? != 0),
+			TWTY_FIX32  => Self::Fix32(read_fix32(bytes, 0)?),
+			TWTY_FRAME  => Self::Frame {
+				left:   read_fix32(bytes, 0)?,
+				top:    read_fix32(bytes, 4)?,
+				right:  read_fix32(bytes, 8)?,
+				bottom: read_fix32(bytes, 12)?,
+			},
+			TWTY_STR32  => Self::Str32(read_str(bytes)),
+			TWTY_STR64  => Self::Str64(read_str(bytes)),
+			TWTY_STR128 => Self::Str128(read_str(bytes)),
+			TWTY_STR255 => Self::Str255(read_str(bytes)),
+			TWTY_HANDLE => Self::Handle(usize::from_ne_bytes(bytes.try_into().ok()?) as TW_UINTPTR),
+			_ => return None,
+		})
+	}
+
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::new();
+		match self {
+			Self::Int8(v)   => bytes.push(*v as u8),
+			Self::Int16(v)  => bytes.extend_from_slice(&v.to_ne_bytes()),
+			Self::Int32(v)  => bytes.extend_from_slice(&v.to_ne_bytes()),
+			Self::UInt8(v)  => bytes.push(*v),
+			Self::UInt16(v) => bytes.extend_from_slice(&v.to_ne_bytes()),
+			Self::UInt32(v) => bytes.extend_from_slice(&v.to_ne_bytes()),
+			Self::Bool(v)   => bytes.extend_from_slice(&(*v as TW_BOOL).to_ne_bytes()),
+			Self::Fix32(v)  => write_fix32(&mut bytes, *v),
+			Self::Frame { left, top, right, bottom } => {
+				for v in [left, top, right, bottom] {
+					write_fix32(&mut bytes, *v);
+				}
+			},
+			Self::Str32(s)  => write_str(&mut bytes, s, mem::size_of::<TW_STR32>()),
+			Self::Str64(s)  => write_str(&mut bytes, s, mem::size_of::<TW_STR64>()),
+			Self::Str128(s) => write_str(&mut bytes, s, mem::size_of::<TW_STR128>()),
+			Self::Str255(s) => write_str(&mut bytes, s, mem::size_of::<TW_STR255>()),
+			Self::Handle(h) => bytes.extend_from_slice(&(*h as usize).to_ne_bytes()),
+		}
+		bytes
+	}
+
+	pub fn from_range_u32(item_type: TW_UINT16, value: TW_UINT32) -> Option<Self> {
+		let mut bytes = value.to_ne_bytes().to_vec();
+		bytes.resize(item_size(item_type)?.max(bytes.len()), 0);
+		Self::from_bytes(item_type, &bytes)
+	}
+
+	pub fn to_range_u32(&self) -> TW_UINT32 {
+		let mut bytes = self.to_bytes();
+		bytes.resize(4, 0);
+		u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+	}
+
+	pub fn item_type(&self) -> TW_UINT16 {
+		(match self {
+			Self::Int8(_)   => TWTY_INT8,
+			Self::Int16(_)  => TWTY_INT16,
+			Self::Int32(_)  => TWTY_INT32,
+			Self::UInt8(_)  => TWTY_UINT8,
+			Self::UInt16(_) => TWTY_UINT16,
+			Self::UInt32(_) => TWTY_UINT32,
+			Self::Bool(_)   => TWTY_BOOL,
+			Self::Fix32(_)  => TWTY_FIX32,
+			Self::Frame { .. } => TWTY_FRAME,
+			Self::Str32(_)  => TWTY_STR32,
+			Self::Str64(_)  => TWTY_STR64,
+			Self::Str128(_) => TWTY_STR128,
+			Self::Str255(_) => TWTY_STR255,
+			Self::Handle(_) => TWTY_HANDLE,
+		}) as TW_UINT16
+	}
+
+	pub fn as_i64(&self) -> Option<i64> {
+		match self {
+			Self::Int8(v)   => Some(*v as i64),
+			Self::Int16(v)  => Some(*v as i64),
+			Self::Int32(v)  => Some(*v as i64),
+			Self::UInt8(v)  => Some(*v as i64),
+			Self::UInt16(v) => Some(*v as i64),
+			Self::UInt32(v) => Some(*v as i64),
+			Self::Bool(v)   => Some(*v as i64),
+			_ => None,
+		}
+	}
+
+	pub fn as_u32(&self) -> Option<u32> {
+		self.as_i64().and_then(|v| u32::try_from(v).ok())
+	}
+
+	pub fn as_f64(&self) -> Option<f64> {
+		match self {
+			Self::Fix32(v) => Some(*v),
+			_ => self.as_i64().map(|v| v as f64),
+		}
+	}
+
+	pub fn as_bool(&self) -> Option<bool> {
+		match self {
+			Self::Bool(v) => Some(*v),
+			_ => self.as_i64().map(|v| v != 0),
+		}
+	}
+
+	pub fn as_str(&self) -> Option<&str> {
+		match self {
+			Self::Str32(s) | Self::Str64(s) | Self::Str128(s) | Self::Str255(s) => Some(s),
+			_ => None,
+		}
+	}
+
+	pub fn with_type(item_type: TW_UINT16, value: i64) -> Option<Self> {
+		Some(match item_type as TwainUConst {
+			TWTY_INT8   => Self::Int8(value.try_into().ok()?),
+			TWTY_INT16  => Self::Int16(value.try_into().ok()?),
+			TWTY_INT32  => Self::Int32(value.try_into().ok()?),
+			TWTY_UINT8  => Self::UInt8(value.try_into().ok()?),
+			TWTY_UINT16 => Self::UInt16(value.try_into().ok()?),
+			TWTY_UINT32 => Self::UInt32(value.try_into().ok()?),
+			TWTY_BOOL   => Self::Bool(value != 0),
+			TWTY_FIX32  => Self::Fix32(value as f64),
+			_ => return None,
+		})
+	}
+}
+
+impl CapContainer {
+	pub fn con_type(&self) -> TW_UINT16 {
+		(match self {
+			Self::OneValue(_)       => TWON_ONEVALUE,
+			Self::Array(_)          => TWON_ARRAY,
+			Self::Enumeration { .. } => TWON_ENUMERATION,
+			Self::Range { .. }      => TWON_RANGE,
+		}) as TW_UINT16
+	}
+
+	pub fn item_type(&self) -> Option<TW_UINT16> {
+		match self {
+			Self::OneValue(v) => Some(v.item_type()),
+			Self::Array(items) | Self::Enumeration { items, .. } => items.first().map(|v| v.item_type()),
+			Self::Range { current, .. } => Some(current.item_type()),
+		}
+	}
+
+	pub fn current(&self) -> Option<&CapValue> {
+		match self {
+			Self::OneValue(v) => Some(v),
+			Self::Array(items) => items.first(),
+			Self::Enumeration { items, current_index, .. } => items.get(*current_index),
+			Self::Range { current, .. } => Some(current),
+		}
+	}
+
+	pub fn default_value(&self) -> Option<&CapValue> {
+		match self {
+			Self::OneValue(v) => Some(v),
+			Self::Array(items) => items.first(),
+			Self::Enumeration { items, default_index, .. } => items.get(*default_index),
+			Self::Range { default, .. } => Some(default),
+		}
+	}
+
+	pub fn values(&self) -> Vec<&CapValue> {
+		match self {
+			Self::OneValue(v) => vec![v],
+			Self::Array(items) | Self::Enumeration { items, .. } => items.iter().collect(),
+			Self::Range { .. } => Vec::new(),
+		}
+	}
+
+	pub fn allows(&self, value: &CapValue) -> bool {
+		match self {
+			Self::Range { min, max, .. } => match (value.as_f64(), min.as_f64(), max.as_f64()) {
+				(Some(v), Some(min), Some(max)) => min <= v && v <= max,
+				_ => false,
+			},
+			_ => self.values().into_iter().any(|v| v == value),
+		}
+	}
+
+	pub fn from_bytes(con_type: TW_UINT16, bytes: &[u8]) -> Option<Self> {
+		let item_type = read_u16(bytes, 0)?;
+
+		let read_items = |offset: usize, count: usize| -> Option<Vec<CapValue>> {
+			let size = item_size(item_type)?;
+			(0..count).map(|i| CapValue::from_bytes(item_type, bytes.get(offset + i * size..)?)).collect()
+		};
+
+		Some(match con_type as TwainUConst {
+			TWON_ONEVALUE => Self::OneValue(CapValue::from_bytes(item_type, bytes.get(ONEVALUE_HEADER_SIZE..)?)?),
+			TWON_ARRAY => {
+				let count = read_u32(bytes, 2)? as usize;
+				Self::Array(read_items(ARRAY_HEADER_SIZE, count)?)
+			},
+			TWON_ENUMERATION => {
+				let count = read_u32(bytes, 2)? as usize;
+				Self::Enumeration {
+					current_index: read_u32(bytes, 6)? as usize,
+					default_index: read_u32(bytes, 10)? as usize,
+					items: read_items(ENUMERATION_HEADER_SIZE, count)?,
+				}
+			},
+			TWON_RANGE => Self::Range {
+				min:     CapValue::from_range_u32(item_type, read_u32(bytes, 2)?)?,
+				max:     CapValue::from_range_u32(item_type, read_u32(bytes, 6)?)?,
+				step:    CapValue::from_range_u32(item_type, read_u32(bytes, 10)?)?,
+				default: CapValue::from_range_u32(item_type, read_u32(bytes, 14)?)?,
+				current: CapValue::from_range_u32(item_type, read_u32(bytes, 18)?)?,
+			},
+			_ => return None,
+		})
+	}
+
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let item_type = self.item_type().unwrap_or(TWTY_UINT16 as TW_UINT16);
+
+		let mut bytes = Vec::new();
+		bytes.extend_from_slice(&item_type.to_ne_bytes());
+
+		match self {
+			Self::OneValue(v) => {
+				bytes.extend(v.to_bytes());
+				bytes.resize(bytes.len().max(mem::size_of::<TW_ONEVALUE>()), 0);
+			},
+			Self::Array(items) => {
+				bytes.extend_from_slice(&(items.len() as TW_UINT32).to_ne_bytes());
+				items.iter().for_each(|v| bytes.extend(v.to_bytes()));
+			},
+			Self::Enumeration { items, current_index, default_index } => {
+				bytes.extend_from_slice(&(items.len() as TW_UINT32).to_ne_bytes());
+				bytes.extend_from_slice(&(*current_index as TW_UINT32).to_ne_bytes());
+				bytes.extend_from_slice(&(*default_index as TW_UINT32).to_ne_bytes());
+				items.iter().for_each(|v| bytes.extend(v.to_bytes()));
+			},
+			Self::Range { min, max, step, default, current } => {
+				for v in [min, max, step, default, current] {
+					bytes.extend_from_slice(&v.to_range_u32().to_ne_bytes());
+				}
+			},
+		}
+
+		bytes
+	}
+
+	// Size in bytes of a whole container, from its first CONTAINER_LEN_HEADER_SIZE bytes
+	pub fn len_from_header(con_type: TW_UINT16, header: &[u8]) -> Option<usize> {
+		let item_type = read_u16(header, 0)?;
+		Some(match con_type as TwainUConst {
+			TWON_ONEVALUE => ONEVALUE_HEADER_SIZE + item_size(item_type)?.max(4),
+			TWON_ARRAY => ARRAY_HEADER_SIZE + item_size(item_type)? * read_u32(header, 2)? as usize,
+			TWON_ENUMERATION => ENUMERATION_HEADER_SIZE + item_size(item_type)? * read_u32(header, 2)? as usize,
+			TWON_RANGE => RANGE_SIZE,
+			_ => return None,
+		})
+	}
+
+	unsafe fn from_ptr(con_type: TW_UINT16, p: *const u8) -> Option<Self> {
+		let len = Self::len_from_header(con_type, std::slice::from_raw_parts(p, CONTAINER_LEN_HEADER_SIZE))?;
+		Self::from_bytes(con_type, std::slice::from_raw_parts(p, len))
+	}
+}
+
+impl QuerySupport {
+	pub fn has(&self, flag: TwainUConst) -> bool {
+		self.0 as TW_UINT32 & flag != 0
+	}
+
+	pub fn get(&self)         -> bool { self.has(TWQC_GET) }
+	pub fn set(&self)         -> bool { self.has(TWQC_SET) }
+	pub fn get_default(&self) -> bool { self.has(TWQC_GETDEFAULT) }
+	pub fn get_current(&self) -> bool { self.has(TWQC_GETCURRENT) }
+	pub fn reset(&self)       -> bool { self.has(TWQC_RESET) }
+}
+
+fn read_container(ep: &EntryPoints, con_type: TW_UINT16, handle: TW_HANDLE) -> Result<CapContainer, DSError> {
+	let p = PointerFromHandle::<u8>::new(ep, handle).ok_or(DSError::MemoryError)?;
+	unsafe { CapContainer::from_ptr(con_type, *p) }.ok_or(DSError::BadContainer(con_type))
+}
+
+impl OpenedDS {
+	pub fn capability_message(&self, cap: TwainUConst, msg: TwainUConst) -> Result<CapContainer, DSError> {
+		let ep = self.dsm.entry_points.as_ref().ok_or(DSError::MemoryError)?;
+
+		let mut capability = TW_CAPABILITY { Cap: cap as TW_UINT16, ConType: TWON_DONTCARE16 as TW_UINT16, hContainer: ptr::null_mut() };
+		let res = self.do_dsm_entry(DG_CONTROL, DAT_CAPABILITY, msg, &mut capability as *mut TW_CAPABILITY as _);
+		if !res.is_success() {
+			return Err(DSError::BadResponse(res));
+		}
+
+		let handle = capability.hContainer;
+		let container = read_container(ep, capability.ConType, handle);
+		(ep.free)(handle);
+
+		container
+	}
+
+	pub fn get_capability(&self, cap: TwainUConst) -> Result<CapContainer, DSError> {
+		self.capability_message(cap, MSG_GET)
+	}
+
+	pub fn get_capability_current(&self, cap: TwainUConst) -> Result<CapContainer, DSError> {
+		self.capability_message(cap, MSG_GETCURRENT)
+	}
+
+	pub fn get_capability_default(&self, cap: TwainUConst) -> Result<CapContainer, DSError> {
+		self.capability_message(cap, MSG_GETDEFAULT)
+	}
+
+	pub fn query_capability_support(&self, cap: TwainUConst) -> Result<QuerySupport, DSError> {
+		let container = self.capability_message(cap, MSG_QUERYSUPPORT)?;
+		container.current()
+			.and_then(|v| v.as_i64())
+			.map(|v| QuerySupport(v as TW_INT32))
+			.ok_or(DSError::BadContainer(container.con_type()))
+	}
+
+	pub fn supported_capabilities(&self) -> Result<Vec<TW_UINT16>, DSError> {
+		let container = self.get_capability(CAP_SUPPORTEDCAPS)?;
+		Ok(container.values().into_iter().filter_map(|v| v.as_u32()).map(|v| v as TW_UINT16).collect())
+	}
+
+	pub fn set_capability(&self, cap: TwainUConst, container: &CapContainer) -> Result<(), DSError> {
+		if self.get_state() != DSState::SourceOpen {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let ep = self.dsm.entry_points.as_ref().ok_or(DSError::MemoryError)?;
+
+		log::debug!("Setting {} on \"{}\" to {}", cap_label(cap as TW_UINT16), self.name, container);
+
+		let bytes = container.to_bytes();
+		let handle = (ep.allocate)(bytes.len() as TW_UINT32);
+		if handle.is_null() {
+			return Err(DSError::MemoryError);
+		}
+
+		match PointerFromHandle::<u8>::new(ep, handle) {
+			Some(p) => unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), *p, bytes.len()) },
+			None => {
+				(ep.free)(handle);
+				return Err(DSError::MemoryError);
+			},
+		}
+
+		let mut capability = TW_CAPABILITY { Cap: cap as TW_UINT16, ConType: container.con_type(), hContainer: handle };
+		let res = self.do_dsm_entry(DG_CONTROL, DAT_CAPABILITY, MSG_SET, &mut capability as *mut TW_CAPABILITY as _);
+		(ep.free)(handle);
+
+		match res.return_code {
+			ReturnCode::Success => Ok(()),
+			ReturnCode::CheckStatus => {
+				log::debug!("{} on \"{}\" was set to a different value than requested", cap_label(cap as TW_UINT16), self.name);
+				Ok(())
+			},
+			_ => Err(DSError::BadResponse(res)),
+		}
+	}
+
+	pub fn set_capability_value(&self, cap: TwainUConst, value: CapValue) -> Result<(), DSError> {
+		self.set_capability(cap, &CapContainer::OneValue(value))
+	}
+
+	pub fn reset_capability(&self, cap: TwainUConst) -> Result<CapContainer, DSError> {
+		if self.get_state() != DSState::SourceOpen {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		self.capability_message(cap, MSG_RESET)
+	}
+}
+
+pub fn cap_name(cap: TW_UINT16) -> Option<&'static str> {
+	Some(match cap as TwainUConst {
+		CAP_XFERCOUNT                        => "CAP_XFERCOUNT",
+		ICAP_COMPRESSION                     => "ICAP_COMPRESSION",
+		ICAP_PIXELTYPE                       => "ICAP_PIXELTYPE",
+		ICAP_UNITS                           => "ICAP_UNITS",
+		ICAP_XFERMECH                        => "ICAP_XFERMECH",
+		CAP_AUTHOR                           => "CAP_AUTHOR",
+		CAP_CAPTION                          => "CAP_CAPTION",
+		CAP_FEEDERENABLED                    => "CAP_FEEDERENABLED",
+		CAP_FEEDERLOADED                     => "CAP_FEEDERLOADED",
+		CAP_TIMEDATE                         => "CAP_TIMEDATE",
+		CAP_SUPPORTEDCAPS                    => "CAP_SUPPORTEDCAPS",
+		CAP_EXTENDEDCAPS                     => "CAP_EXTENDEDCAPS",
+		CAP_AUTOFEED                         => "CAP_AUTOFEED",
+		CAP_CLEARPAGE                        => "CAP_CLEARPAGE",
+		CAP_FEEDPAGE                         => "CAP_FEEDPAGE",
+		CAP_REWINDPAGE                       => "CAP_REWINDPAGE",
+		CAP_INDICATORS                       => "CAP_INDICATORS",
+		CAP_PAPERDETECTABLE                  => "CAP_PAPERDETECTABLE",
+		CAP_UICONTROLLABLE                   => "CAP_UICONTROLLABLE",
+		CAP_DEVICEONLINE                     => "CAP_DEVICEONLINE",
+		CAP_AUTOSCAN                         => "CAP_AUTOSCAN",
+		CAP_THUMBNAILSENABLED                => "CAP_THUMBNAILSENABLED",
+		CAP_DUPLEX                           => "CAP_DUPLEX",
+		CAP_DUPLEXENABLED                    => "CAP_DUPLEXENABLED",
+		CAP_ENABLEDSUIONLY                   => "CAP_ENABLEDSUIONLY",
+		CAP_CUSTOMDSDATA                     => "CAP_CUSTOMDSDATA",
+		CAP_ENDORSER                         => "CAP_ENDORSER",
+		CAP_JOBCONTROL                       => "CAP_JOBCONTROL",
+		CAP_ALARMS                           => "CAP_ALARMS",
+		CAP_ALARMVOLUME                      => "CAP_ALARMVOLUME",
+		CAP_AUTOMATICCAPTURE                 => "CAP_AUTOMATICCAPTURE",
+		CAP_TIMEBEFOREFIRSTCAPTURE           => "CAP_TIMEBEFOREFIRSTCAPTURE",
+		CAP_TIMEBETWEENCAPTURES              => "CAP_TIMEBETWEENCAPTURES",
+		CAP_MAXBATCHBUFFERS                  => "CAP_MAXBATCHBUFFERS",
+		CAP_DEVICETIMEDATE                   => "CAP_DEVICETIMEDATE",
+		CAP_POWERSUPPLY                      => "CAP_POWERSUPPLY",
+		CAP_CAMERAPREVIEWUI                  => "CAP_CAMERAPREVIEWUI",
+		CAP_DEVICEEVENT                      => "CAP_DEVICEEVENT",
+		CAP_SERIALNUMBER                     => "CAP_SERIALNUMBER",
+		CAP_PRINTER                          => "CAP_PRINTER",
+		CAP_PRINTERENABLED                   => "CAP_PRINTERENABLED",
+		CAP_PRINTERINDEX                     => "CAP_PRINTERINDEX",
+		CAP_PRINTERMODE                      => "CAP_PRINTERMODE",
+		CAP_PRINTERSTRING                    => "CAP_PRINTERSTRING",
+		CAP_PRINTERSUFFIX                    => "CAP_PRINTERSUFFIX",
+		CAP_LANGUAGE                         => "CAP_LANGUAGE",
+		CAP_FEEDERALIGNMENT                  => "CAP_FEEDERALIGNMENT",
+		CAP_FEEDERORDER                      => "CAP_FEEDERORDER",
+		CAP_REACQUIREALLOWED                 => "CAP_REACQUIREALLOWED",
+		CAP_BATTERYMINUTES                   => "CAP_BATTERYMINUTES",
+		CAP_BATTERYPERCENTAGE                => "CAP_BATTERYPERCENTAGE",
+		CAP_CAMERASIDE                       => "CAP_CAMERASIDE",
+		CAP_SEGMENTED                        => "CAP_SEGMENTED",
+		CAP_CAMERAENABLED                    => "CAP_CAMERAENABLED",
+		CAP_CAMERAORDER                      => "CAP_CAMERAORDER",
+		CAP_MICRENABLED                      => "CAP_MICRENABLED",
+		CAP_FEEDERPREP                       => "CAP_FEEDERPREP",
+		CAP_FEEDERPOCKET                     => "CAP_FEEDERPOCKET",
+		CAP_AUTOMATICSENSEMEDIUM             => "CAP_AUTOMATICSENSEMEDIUM",
+		CAP_CUSTOMINTERFACEGUID              => "CAP_CUSTOMINTERFACEGUID",
+		CAP_SUPPORTEDCAPSSEGMENTUNIQUE       => "CAP_SUPPORTEDCAPSSEGMENTUNIQUE",
+		CAP_SUPPORTEDDATS                    => "CAP_SUPPORTEDDATS",
+		CAP_DOUBLEFEEDDETECTION              => "CAP_DOUBLEFEEDDETECTION",
+		CAP_DOUBLEFEEDDETECTIONLENGTH        => "CAP_DOUBLEFEEDDETECTIONLENGTH",
+		CAP_DOUBLEFEEDDETECTIONSENSITIVITY   => "CAP_DOUBLEFEEDDETECTIONSENSITIVITY",
+		CAP_DOUBLEFEEDDETECTIONRESPONSE      => "CAP_DOUBLEFEEDDETECTIONRESPONSE",
+		CAP_PAPERHANDLING                    => "CAP_PAPERHANDLING",
+		CAP_INDICATORSMODE                   => "CAP_INDICATORSMODE",
+		CAP_PRINTERVERTICALOFFSET            => "CAP_PRINTERVERTICALOFFSET",
+		CAP_POWERSAVETIME                    => "CAP_POWERSAVETIME",
+		CAP_PRINTERCHARROTATION              => "CAP_PRINTERCHARROTATION",
+		CAP_PRINTERFONTSTYLE                 => "CAP_PRINTERFONTSTYLE",
+		CAP_PRINTERINDEXLEADCHAR             => "CAP_PRINTERINDEXLEADCHAR",
+		CAP_PRINTERINDEXMAXVALUE             => "CAP_PRINTERINDEXMAXVALUE",
+		CAP_PRINTERINDEXNUMDIGITS            => "CAP_PRINTERINDEXNUMDIGITS",
+		CAP_PRINTERINDEXSTEP                 => "CAP_PRINTERINDEXSTEP",
+		CAP_PRINTERINDEXTRIGGER              => "CAP_PRINTERINDEXTRIGGER",
+		CAP_PRINTERSTRINGPREVIEW             => "CAP_PRINTERSTRINGPREVIEW",
+		CAP_SHEETCOUNT                       => "CAP_SHEETCOUNT",
+		CAP_IMAGEADDRESSENABLED              => "CAP_IMAGEADDRESSENABLED",
+		CAP_IAFIELDA_LEVEL                   => "CAP_IAFIELDA_LEVEL",
+		CAP_IAFIELDB_LEVEL                   => "CAP_IAFIELDB_LEVEL",
+		CAP_IAFIELDC_LEVEL                   => "CAP_IAFIELDC_LEVEL",
+		CAP_IAFIELDD_LEVEL                   => "CAP_IAFIELDD_LEVEL",
+		CAP_IAFIELDE_LEVEL                   => "CAP_IAFIELDE_LEVEL",
+		CAP_IAFIELDA_PRINTFORMAT             => "CAP_IAFIELDA_PRINTFORMAT",
+		CAP_IAFIELDB_PRINTFORMAT             => "CAP_IAFIELDB_PRINTFORMAT",
+		CAP_IAFIELDC_PRINTFORMAT             => "CAP_IAFIELDC_PRINTFORMAT",
+		CAP_IAFIELDD_PRINTFORMAT             => "CAP_IAFIELDD_PRINTFORMAT",
+		CAP_IAFIELDE_PRINTFORMAT             => "CAP_IAFIELDE_PRINTFORMAT",
+		CAP_IAFIELDA_VALUE                   => "CAP_IAFIELDA_VALUE",
+		CAP_IAFIELDB_VALUE                   => "CAP_IAFIELDB_VALUE",
+		CAP_IAFIELDC_VALUE                   => "CAP_IAFIELDC_VALUE",
+		CAP_IAFIELDD_VALUE                   => "CAP_IAFIELDD_VALUE",
+		CAP_IAFIELDE_VALUE                   => "CAP_IAFIELDE_VALUE",
+		CAP_IAFIELDA_LASTPAGE                => "CAP_IAFIELDA_LASTPAGE",
+		CAP_IAFIELDB_LASTPAGE                => "CAP_IAFIELDB_LASTPAGE",
+		CAP_IAFIELDC_LASTPAGE                => "CAP_IAFIELDC_LASTPAGE",
+		CAP_IAFIELDD_LASTPAGE                => "CAP_IAFIELDD_LASTPAGE",
+		CAP_IAFIELDE_LASTPAGE                => "CAP_IAFIELDE_LASTPAGE",
+		ICAP_AUTOBRIGHT                      => "ICAP_AUTOBRIGHT",
+		ICAP_BRIGHTNESS                      => "ICAP_BRIGHTNESS",
+		ICAP_CONTRAST                        => "ICAP_CONTRAST",
+		ICAP_CUSTHALFTONE                    => "ICAP_CUSTHALFTONE",
+		ICAP_EXPOSURETIME                    => "ICAP_EXPOSURETIME",
+		ICAP_FILTER                          => "ICAP_FILTER",
+		ICAP_FLASHUSED                       => "ICAP_FLASHUSED",
+		ICAP_GAMMA                           => "ICAP_GAMMA",
+		ICAP_HALFTONES                       => "ICAP_HALFTONES",
+		ICAP_HIGHLIGHT                       => "ICAP_HIGHLIGHT",
+		ICAP_IMAGEFILEFORMAT                 => "ICAP_IMAGEFILEFORMAT",
+		ICAP_LAMPSTATE                       => "ICAP_LAMPSTATE",
+		ICAP_LIGHTSOURCE                     => "ICAP_LIGHTSOURCE",
+		ICAP_ORIENTATION                     => "ICAP_ORIENTATION",
+		ICAP_PHYSICALWIDTH                   => "ICAP_PHYSICALWIDTH",
+		ICAP_PHYSICALHEIGHT                  => "ICAP_PHYSICALHEIGHT",
+		ICAP_SHADOW                          => "ICAP_SHADOW",
+		ICAP_FRAMES                          => "ICAP_FRAMES",
+		ICAP_XNATIVERESOLUTION               => "ICAP_XNATIVERESOLUTION",
+		ICAP_YNATIVERESOLUTION               => "ICAP_YNATIVERESOLUTION",
+		ICAP_XRESOLUTION                     => "ICAP_XRESOLUTION",
+		ICAP_YRESOLUTION                     => "ICAP_YRESOLUTION",
+		ICAP_MAXFRAMES                       => "ICAP_MAXFRAMES",
+		ICAP_TILES                           => "ICAP_TILES",
+		ICAP_BITORDER                        => "ICAP_BITORDER",
+		ICAP_CCITTKFACTOR                    => "ICAP_CCITTKFACTOR",
+		ICAP_LIGHTPATH                       => "ICAP_LIGHTPATH",
+		ICAP_PIXELFLAVOR                     => "ICAP_PIXELFLAVOR",
+		ICAP_PLANARCHUNKY                    => "ICAP_PLANARCHUNKY",
+		ICAP_ROTATION                        => "ICAP_ROTATION",
+		ICAP_SUPPORTEDSIZES                  => "ICAP_SUPPORTEDSIZES",
+		ICAP_THRESHOLD                       => "ICAP_THRESHOLD",
+		ICAP_XSCALING                        => "ICAP_XSCALING",
+		ICAP_YSCALING                        => "ICAP_YSCALING",
+		ICAP_BITORDERCODES                   => "ICAP_BITORDERCODES",
+		ICAP_PIXELFLAVORCODES                => "ICAP_PIXELFLAVORCODES",
+		ICAP_JPEGPIXELTYPE                   => "ICAP_JPEGPIXELTYPE",
+		ICAP_TIMEFILL                        => "ICAP_TIMEFILL",
+		ICAP_BITDEPTH                        => "ICAP_BITDEPTH",
+		ICAP_BITDEPTHREDUCTION               => "ICAP_BITDEPTHREDUCTION",
+		ICAP_UNDEFINEDIMAGESIZE              => "ICAP_UNDEFINEDIMAGESIZE",
+		ICAP_IMAGEDATASET                    => "ICAP_IMAGEDATASET",
+		ICAP_EXTIMAGEINFO                    => "ICAP_EXTIMAGEINFO",
+		ICAP_MINIMUMHEIGHT                   => "ICAP_MINIMUMHEIGHT",
+		ICAP_MINIMUMWIDTH                    => "ICAP_MINIMUMWIDTH",
+		ICAP_AUTODISCARDBLANKPAGES           => "ICAP_AUTODISCARDBLANKPAGES",
+		ICAP_FLIPROTATION                    => "ICAP_FLIPROTATION",
+		ICAP_BARCODEDETECTIONENABLED         => "ICAP_BARCODEDETECTIONENABLED",
+		ICAP_SUPPORTEDBARCODETYPES           => "ICAP_SUPPORTEDBARCODETYPES",
+		ICAP_BARCODEMAXSEARCHPRIORITIES      => "ICAP_BARCODEMAXSEARCHPRIORITIES",
+		ICAP_BARCODESEARCHPRIORITIES         => "ICAP_BARCODESEARCHPRIORITIES",
+		ICAP_BARCODESEARCHMODE               => "ICAP_BARCODESEARCHMODE",
+		ICAP_BARCODEMAXRETRIES               => "ICAP_BARCODEMAXRETRIES",
+		ICAP_BARCODETIMEOUT                  => "ICAP_BARCODETIMEOUT",
+		ICAP_ZOOMFACTOR                      => "ICAP_ZOOMFACTOR",
+		ICAP_PATCHCODEDETECTIONENABLED       => "ICAP_PATCHCODEDETECTIONENABLED",
+		ICAP_SUPPORTEDPATCHCODETYPES         => "ICAP_SUPPORTEDPATCHCODETYPES",
+		ICAP_PATCHCODEMAXSEARCHPRIORITIES    => "ICAP_PATCHCODEMAXSEARCHPRIORITIES",
+		ICAP_PATCHCODESEARCHPRIORITIES       => "ICAP_PATCHCODESEARCHPRIORITIES",
+		ICAP_PATCHCODESEARCHMODE             => "ICAP_PATCHCODESEARCHMODE",
+		ICAP_PATCHCODEMAXRETRIES             => "ICAP_PATCHCODEMAXRETRIES",
+		ICAP_PATCHCODETIMEOUT                => "ICAP_PATCHCODETIMEOUT",
+		ICAP_FLASHUSED2                      => "ICAP_FLASHUSED2",
+		ICAP_IMAGEFILTER                     => "ICAP_IMAGEFILTER",
+		ICAP_NOISEFILTER                     => "ICAP_NOISEFILTER",
+		ICAP_OVERSCAN                        => "ICAP_OVERSCAN",
+		ICAP_AUTOMATICBORDERDETECTION        => "ICAP_AUTOMATICBORDERDETECTION",
+		ICAP_AUTOMATICDESKEW                 => "ICAP_AUTOMATICDESKEW",
+		ICAP_AUTOMATICROTATE                 => "ICAP_AUTOMATICROTATE",
+		ICAP_JPEGQUALITY                     => "ICAP_JPEGQUALITY",
+		ICAP_FEEDERTYPE                      => "ICAP_FEEDERTYPE",
+		ICAP_ICCPROFILE                      => "ICAP_ICCPROFILE",
+		ICAP_AUTOSIZE                        => "ICAP_AUTOSIZE",
+		ICAP_AUTOMATICCROPUSESFRAME          => "ICAP_AUTOMATICCROPUSESFRAME",
+		ICAP_AUTOMATICLENGTHDETECTION        => "ICAP_AUTOMATICLENGTHDETECTION",
+		ICAP_AUTOMATICCOLORENABLED           => "ICAP_AUTOMATICCOLORENABLED",
+		ICAP_AUTOMATICCOLORNONCOLORPIXELTYPE => "ICAP_AUTOMATICCOLORNONCOLORPIXELTYPE",
+		ICAP_COLORMANAGEMENTENABLED          => "ICAP_COLORMANAGEMENTENABLED",
+		ICAP_IMAGEMERGE                      => "ICAP_IMAGEMERGE",
+		ICAP_IMAGEMERGEHEIGHTTHRESHOLD       => "ICAP_IMAGEMERGEHEIGHTTHRESHOLD",
+		ICAP_SUPPORTEDEXTIMAGEINFO           => "ICAP_SUPPORTEDEXTIMAGEINFO",
+		ICAP_FILMTYPE                        => "ICAP_FILMTYPE",
+		ICAP_MIRROR                          => "ICAP_MIRROR",
+		ICAP_JPEGSUBSAMPLING                 => "ICAP_JPEGSUBSAMPLING",
+		ACAP_XFERMECH                        => "ACAP_XFERMECH",
+		_ => return None,
+	})
+}
+
+pub fn cap_label(cap: TW_UINT16) -> String {
+	match cap_name(cap) {
+		Some(name) => String::from(name),
+		None => format!("0x{:04x}", cap),
+	}
+}
+
+pub fn item_type_name(item_type: TW_UINT16) -> &'static str {
+	match item_type as TwainUConst {
+		TWTY_INT8   => "TWTY_INT8",
+		TWTY_INT16  => "TWTY_INT16",
+		TWTY_INT32  => "TWTY_INT32",
+		TWTY_UINT8  => "TWTY_UINT8",
+		TWTY_UINT16 => "TWTY_UINT16",
+		TWTY_UINT32 => "TWTY_UINT32",
+		TWTY_BOOL   => "TWTY_BOOL",
+		TWTY_FIX32  => "TWTY_FIX32",
+		TWTY_FRAME  => "TWTY_FRAME",
+		TWTY_STR32  => "TWTY_STR32",
+		TWTY_STR64  => "TWTY_STR64",
+		TWTY_STR128 => "TWTY_STR128",
+		TWTY_STR255 => "TWTY_STR255",
+		TWTY_HANDLE => "TWTY_HANDLE",
+		_           => "TWTY_?",
+	}
+}
+
+impl fmt::Display for CapValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Int8(v)   => write!(f, "{}", v),
+			Self::Int16(v)  => write!(f, "{}", v),
+			Self::Int32(v)  => write!(f, "{}", v),
+			Self::UInt8(v)  => write!(f, "{}", v),
+			Self::UInt16(v) => write!(f, "{}", v),
+			Self::UInt32(v) => write!(f, "{}", v),
+			Self::Bool(v)   => write!(f, "{}", v),
+			Self::Fix32(v)  => write!(f, "{}", v),
+			Self::Frame { left, top, right, bottom } => write!(f, "({}, {}, {}, {})", left, top, right, bottom),
+			Self::Str32(s) | Self::Str64(s) | Self::Str128(s) | Self::Str255(s) => write!(f, "\"{}\"", s),
+			Self::Handle(h) => write!(f, "{:#x}", h),
+		}
+	}
+}
+
+impl fmt::Display for CapContainer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		let join = |items: &[CapValue]| items.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+		match self {
+			Self::OneValue(v) => write!(f, "{}", v),
+			Self::Array(items) => write!(f, "[{}]", join(items)),
+			Self::Enumeration { items, current_index, default_index } => write!(f, "{{{}}} current={} default={}", join(items), current_index, default_index),
+			Self::Range { min, max, step, default, current } => write!(f, "{}..{} step {} current={} default={}", min, max, step, current, default),
+		}
+	}
+}
+
+impl fmt::Display for QuerySupport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		let names = [
+			(TWQC_GET,        "Get"),
+			(TWQC_SET,        "Set"),
+			(TWQC_GETDEFAULT, "GetDefault"),
+			(TWQC_GETCURRENT, "GetCurrent"),
+			(TWQC_RESET,      "Reset"),
+		];
+		let set: Vec<&str> = names.iter().filter(|(flag, _)| self.has(*flag)).map(|(_, name)| *name).collect();
+		write!(f, "{}", set.join("|"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fix32_round_trip() {
+		for v in [0.0, 1.5, -1.5, 300.25, -0.75] {
+			assert_eq!(v, fix32_to_f64(f64_to_fix32(v)));
+		}
+	}
+
+	#[test]
+	fn onevalue_round_trip() {
+		let container = CapContainer::OneValue(CapValue::UInt16(TWPT_RGB as TW_UINT16));
+		let bytes = container.to_bytes();
+		assert_eq!(mem::size_of::<TW_ONEVALUE>(), bytes.len());
+		assert_eq!(Some(container), CapContainer::from_bytes(TWON_ONEVALUE as TW_UINT16, &bytes));
+	}
+
+	#[test]
+	fn enumeration_round_trip() {
+		let container = CapContainer::Enumeration {
+			items: vec![CapValue::Fix32(100.0), CapValue::Fix32(200.0), CapValue::Fix32(300.0)],
+			current_index: 1,
+			default_index: 2,
+		};
+		let bytes = container.to_bytes();
+		let parsed = CapContainer::from_bytes(TWON_ENUMERATION as TW_UINT16, &bytes).unwrap();
+		assert_eq!(Some(&CapValue::Fix32(200.0)), parsed.current());
+		assert_eq!(Some(&CapValue::Fix32(300.0)), parsed.default_value());
+		assert_eq!(container, parsed);
+	}
+
+	#[test]
+	fn range_round_trip() {
+		let container = CapContainer::Range {
+			min: CapValue::Int16(-10),
+			max: CapValue::Int16(10),
+			step: CapValue::Int16(2),
+			default: CapValue::Int16(0),
+			current: CapValue::Int16(-4),
+		};
+		let bytes = container.to_bytes();
+		assert_eq!(RANGE_SIZE, bytes.len());
+		assert_eq!(Some(container.clone()), CapContainer::from_bytes(TWON_RANGE as TW_UINT16, &bytes));
+		assert!(container.allows(&CapValue::Int16(5)));
+		assert!(!container.allows(&CapValue::Int16(11)));
+	}
+
+	#[test]
+	fn string_array_round_trip() {
+		let container = CapContainer::Array(vec![CapValue::Str32(String::from("one")), CapValue::Str32(String::from("two"))]);
+		let bytes = container.to_bytes();
+		assert_eq!(ARRAY_HEADER_SIZE + 2 * STR32_LEN, bytes.len());
+		assert_eq!(Some(container), CapContainer::from_bytes(TWON_ARRAY as TW_UINT16, &bytes));
+	}
+}
@@ -0,0 +1,101 @@
+use super::{DSError, OpenedDS};
+use super::capability::*;
+use super::extimageinfo::TweiId;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+// Settings shared by the detectors that report through extended image info, barcodes and patch codes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectionConfig {
+	pub enabled: bool,
+	pub search_priorities: Vec<TW_UINT16>,
+	pub search_mode: Option<TW_UINT16>,
+	pub max_retries: Option<TW_UINT32>,
+	pub timeout: Option<TW_UINT32>,
+}
+
+// The capabilities and TWEI_ ids of one detector
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DetectionCapabilities {
+	pub enabled: TwainUConst,
+	pub search_priorities: TwainUConst,
+	pub search_mode: TwainUConst,
+	pub max_retries: TwainUConst,
+	pub timeout: TwainUConst,
+	pub ext_image_info: &'static [TwainUConst],
+}
+
+impl Default for DetectionConfig {
+	fn default() -> Self {
+		Self {
+			enabled: true,
+			search_priorities: Vec::new(),
+			search_mode: None,
+			max_retries: None,
+			timeout: None,
+		}
+	}
+}
+
+impl OpenedDS {
+	pub fn configure_detection(&self, caps: &DetectionCapabilities, config: &DetectionConfig) -> Result<(), DSError> {
+		self.set_capability_value(caps.enabled, CapValue::Bool(config.enabled))?;
+
+		let ids: Vec<TweiId> = caps.ext_image_info.iter().map(|id| *id as TweiId).collect();
+		if !config.enabled {
+			self.remove_ext_image_info(&ids)?;
+			return Ok(());
+		}
+
+		if !config.search_priorities.is_empty() {
+			let priorities = config.search_priorities.iter().map(|t| CapValue::UInt16(*t)).collect();
+			self.set_capability(caps.search_priorities, &CapContainer::Array(priorities))?;
+		}
+		if let Some(mode) = config.search_mode {
+			self.set_capability_value(caps.search_mode, CapValue::UInt16(mode))?;
+		}
+		if let Some(retries) = config.max_retries {
+			self.set_capability_value(caps.max_retries, CapValue::UInt32(retries))?;
+		}
+		if let Some(timeout) = config.timeout {
+			self.set_capability_value(caps.timeout, CapValue::UInt32(timeout))?;
+		}
+
+		self.add_ext_image_info(&ids)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::barcode::*;
+	use super::super::fake::*;
+	use super::super::patchcode::*;
+
+	#[test]
+	fn disabling_removes_ext_image_info() {
+		let (wrapper, source) = fake_dsm();
+		{
+			let mut source = source.lock();
+			let supported = BARCODE_EXT_IMAGE_INFO.iter().chain([TWEI_PATCHCODE].iter()).map(|id| CapValue::UInt16(*id as TW_UINT16)).collect();
+			source.capabilities.insert(ICAP_SUPPORTEDEXTIMAGEINFO as TW_UINT16, (CapContainer::Array(supported), false));
+			source.add_capability(ICAP_EXTIMAGEINFO, CapValue::Bool(false), true);
+			source.add_capability(ICAP_BARCODEDETECTIONENABLED, CapValue::Bool(false), true);
+			source.add_capability(ICAP_PATCHCODEDETECTIONENABLED, CapValue::Bool(false), true);
+		}
+		let ds = open_fake_source(wrapper);
+
+		ds.configure_barcodes(&Default::default()).unwrap();
+		ds.configure_patch_codes(&Default::default()).unwrap();
+		assert_eq!(BARCODE_EXT_IMAGE_INFO.len() + 1, ds.ext_image_info_ids.read().len());
+
+		ds.configure_barcodes(&BarcodeConfig { enabled: false, ..Default::default() }).unwrap();
+		assert_eq!(vec![TWEI_PATCHCODE as TweiId], *ds.ext_image_info_ids.read());
+
+		ds.configure_patch_codes(&PatchCodeConfig { enabled: false, ..Default::default() }).unwrap();
+		assert!(ds.ext_image_info_ids.read().is_empty());
+		assert_eq!(Some(false), ds.get_capability_current(ICAP_EXTIMAGEINFO).unwrap().current().and_then(|v| v.as_bool()));
+	}
+}
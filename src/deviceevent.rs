@@ -0,0 +1,278 @@
+use super::{DSError, DSState, OpenedDS};
+use super::capability::*;
+use super::response::*;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
+
+pub type DeviceEventSubscriber = Arc<dyn Fn(&DeviceEvent) + Send + Sync>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DeviceEventKind {
+	CheckAutomaticCapture,
+	CheckBattery,
+	CheckDeviceOnline,
+	CheckFlash,
+	CheckPowerSupply,
+	CheckResolution,
+	DeviceAdded,
+	DeviceOffline,
+	DeviceReady,
+	DeviceRemoved,
+	ImageCaptured,
+	ImageDeleted,
+	PaperDoubleFeed,
+	PaperJam,
+	LampFailure,
+	PowerSave,
+	PowerSaveNotify,
+	// Vendor specific, TWDE_CUSTOMEVENTS and up
+	Custom(TW_UINT16),
+	Unknown(TW_UINT16),
+}
+
+// Only the fields relevant to the event kind are filled in by the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceEvent {
+	pub kind: DeviceEventKind,
+	pub device_name: String,
+	pub battery_minutes: TW_UINT32,
+	pub battery_percentage: TW_INT16,
+	pub power_supply: TW_INT32,
+	pub x_resolution: f64,
+	pub y_resolution: f64,
+	pub flash_used: TW_UINT32,
+	pub automatic_capture: TW_UINT32,
+	pub time_before_first_capture: TW_UINT32,
+	pub time_between_captures: TW_UINT32,
+}
+
+impl DeviceEventKind {
+	pub fn from_twde(value: TW_UINT16) -> Self {
+		match value as TwainUConst {
+			TWDE_CHECKAUTOMATICCAPTURE => Self::CheckAutomaticCapture,
+			TWDE_CHECKBATTERY          => Self::CheckBattery,
+			TWDE_CHECKDEVICEONLINE     => Self::CheckDeviceOnline,
+			TWDE_CHECKFLASH            => Self::CheckFlash,
+			TWDE_CHECKPOWERSUPPLY      => Self::CheckPowerSupply,
+			TWDE_CHECKRESOLUTION       => Self::CheckResolution,
+			TWDE_DEVICEADDED           => Self::DeviceAdded,
+			TWDE_DEVICEOFFLINE         => Self::DeviceOffline,
+			TWDE_DEVICEREADY           => Self::DeviceReady,
+			TWDE_DEVICEREMOVED         => Self::DeviceRemoved,
+			TWDE_IMAGECAPTURED         => Self::ImageCaptured,
+			TWDE_IMAGEDELETED          => Self::ImageDeleted,
+			TWDE_PAPERDOUBLEFEED       => Self::PaperDoubleFeed,
+			TWDE_PAPERJAM              => Self::PaperJam,
+			TWDE_LAMPFAILURE           => Self::LampFailure,
+			TWDE_POWERSAVE             => Self::PowerSave,
+			TWDE_POWERSAVENOTIFY       => Self::PowerSaveNotify,
+			v if v >= TWDE_CUSTOMEVENTS => Self::Custom(value),
+			_ => Self::Unknown(value),
+		}
+	}
+
+	pub fn to_twde(&self) -> TW_UINT16 {
+		(match self {
+			Self::CheckAutomaticCapture => TWDE_CHECKAUTOMATICCAPTURE,
+			Self::CheckBattery          => TWDE_CHECKBATTERY,
+			Self::CheckDeviceOnline     => TWDE_CHECKDEVICEONLINE,
+			Self::CheckFlash            => TWDE_CHECKFLASH,
+			Self::CheckPowerSupply      => TWDE_CHECKPOWERSUPPLY,
+			Self::CheckResolution       => TWDE_CHECKRESOLUTION,
+			Self::DeviceAdded           => TWDE_DEVICEADDED,
+			Self::DeviceOffline         => TWDE_DEVICEOFFLINE,
+			Self::DeviceReady           => TWDE_DEVICEREADY,
+			Self::DeviceRemoved         => TWDE_DEVICEREMOVED,
+			Self::ImageCaptured         => TWDE_IMAGECAPTURED,
+			Self::ImageDeleted          => TWDE_IMAGEDELETED,
+			Self::PaperDoubleFeed       => TWDE_PAPERDOUBLEFEED,
+			Self::PaperJam              => TWDE_PAPERJAM,
+			Self::LampFailure           => TWDE_LAMPFAILURE,
+			Self::PowerSave             => TWDE_POWERSAVE,
+			Self::PowerSaveNotify       => TWDE_POWERSAVENOTIFY,
+			Self::Custom(value) | Self::Unknown(value) => return *value,
+		}) as TW_UINT16
+	}
+}
+
+impl From<TW_DEVICEEVENT> for DeviceEvent {
+	fn from(event: TW_DEVICEEVENT) -> Self {
+		let device_name = event.DeviceName;
+		Self {
+			kind: DeviceEventKind::from_twde(event.Event as TW_UINT16),
+			device_name: tw_str255_to_string(&device_name),
+			battery_minutes: event.BatteryMinutes,
+			battery_percentage: event.BatteryPercentage,
+			power_supply: event.PowerSupply,
+			x_resolution: fix32_to_f64(event.XResolution),
+			y_resolution: fix32_to_f64(event.YResolution),
+			flash_used: event.FlashUsed2,
+			automatic_capture: event.AutomaticCapture,
+			time_before_first_capture: event.TimeBeforeFirstCapture,
+			time_between_captures: event.TimeBetweenCaptures,
+		}
+	}
+}
+
+impl OpenedDS {
+	pub fn supported_device_events(&self) -> Result<Vec<DeviceEventKind>, DSError> {
+		let container = self.get_capability(CAP_DEVICEEVENT)?;
+		Ok(container.values().iter().filter_map(|v| v.as_u32()).map(|v| DeviceEventKind::from_twde(v as TW_UINT16)).collect())
+	}
+
+	// An empty list turns device events off again
+	pub fn set_device_events(&self, events: &[DeviceEventKind]) -> Result<(), DSError> {
+		if self.get_state() != DSState::SourceOpen {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let values = events.iter().map(|e| CapValue::UInt16(e.to_twde())).collect();
+		self.set_capability(CAP_DEVICEEVENT, &CapContainer::Array(values))
+	}
+
+	pub fn subscribe_device_events<F: Fn(&DeviceEvent) + Send + Sync + 'static>(&self, subscriber: F) {
+		self.device_event_subscribers.write().push(Arc::new(subscriber));
+	}
+
+	pub fn clear_device_event_subscribers(&self) {
+		self.device_event_subscribers.write().clear();
+	}
+
+	// Number of MSG_DEVICEEVENT notifications received since the last dispatch_device_events()
+	pub fn device_events_pending(&self) -> usize {
+		*self.pending_device_events.read()
+	}
+
+	// Drains the queued events from the source and hands each one to the subscribers.
+	// Must not be called from within the callback, the source expects MSG_GET from the application thread.
+	pub fn dispatch_device_events(&self) -> Result<Vec<DeviceEvent>, DSError> {
+		let mut events = Vec::new();
+
+		loop {
+			let mut tw_event: MaybeUninit<TW_DEVICEEVENT> = MaybeUninit::uninit();
+			let res = self.do_dsm_entry(DG_CONTROL, DAT_DEVICEEVENT, MSG_GET, tw_event.as_mut_ptr() as _);
+			if !res.is_success() {
+				// TWCC_SEQERROR means the queue is empty
+				if res.condition_code == ConditionCode::SeqError || !events.is_empty() {
+					break;
+				}
+				return Err(DSError::BadResponse(res));
+			}
+
+			let event = DeviceEvent::from(unsafe { tw_event.assume_init() });
+			log::debug!("Device event on \"{}\": {}", self.name, event);
+			events.push(event);
+		}
+
+		*self.pending_device_events.write() = 0;
+
+		// Cloned so subscribers can subscribe or unsubscribe without deadlocking
+		let subscribers = self.device_event_subscribers.read().clone();
+		for event in &events {
+			subscribers.iter().for_each(|subscriber| subscriber(event));
+		}
+
+		Ok(events)
+	}
+}
+
+impl fmt::Display for DeviceEventKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Custom(value)  => write!(f, "Custom({})", value),
+			Self::Unknown(value) => write!(f, "Unknown({})", value),
+			kind                 => write!(f, "{:?}", kind),
+		}
+	}
+}
+
+impl fmt::Display for DeviceEvent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		write!(f, "{} on \"{}\"", self.kind, self.device_name)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::fake::*;
+
+	use parking_lot::Mutex;
+
+	#[test]
+	fn device_event_kind_round_trip() {
+		for value in TWDE_CHECKAUTOMATICCAPTURE..=TWDE_POWERSAVENOTIFY {
+			let kind = DeviceEventKind::from_twde(value as TW_UINT16);
+			assert!(!matches!(kind, DeviceEventKind::Unknown(_) | DeviceEventKind::Custom(_)));
+			assert_eq!(value as TW_UINT16, kind.to_twde());
+		}
+		assert_eq!(DeviceEventKind::Custom(0x8001), DeviceEventKind::from_twde(0x8001));
+		assert_eq!(DeviceEventKind::Unknown(100), DeviceEventKind::from_twde(100));
+	}
+
+	fn tw_device_event(event: TwainUConst) -> TW_DEVICEEVENT {
+		TW_DEVICEEVENT {
+			Event: event,
+			DeviceName: tw_str255("Front feeder"),
+			BatteryMinutes: 0,
+			BatteryPercentage: 0,
+			PowerSupply: 0,
+			XResolution: f64_to_fix32(300.0),
+			YResolution: f64_to_fix32(300.0),
+			FlashUsed2: 0,
+			AutomaticCapture: 0,
+			TimeBeforeFirstCapture: 0,
+			TimeBetweenCaptures: 0,
+		}
+	}
+
+	#[test]
+	fn subscribers_can_subscribe_during_dispatch() {
+		let (wrapper, source) = fake_dsm();
+		source.lock().device_events.extend([tw_device_event(TWDE_PAPERJAM), tw_device_event(TWDE_DEVICEREADY)]);
+		let ds = open_fake_source(wrapper);
+
+		// OpenedDS isn't Send, subscribers reach it through its address like the DSM callback does
+		let ds_address = &*ds as *const OpenedDS as usize;
+		let seen = Arc::new(Mutex::new(Vec::new()));
+		let subscriber_seen = seen.clone();
+		ds.subscribe_device_events(move |event| {
+			subscriber_seen.lock().push(event.kind);
+			if event.kind == DeviceEventKind::PaperJam {
+				let ds = unsafe { &*(ds_address as *const OpenedDS) };
+				ds.subscribe_device_events(|_| {});
+			}
+		});
+
+		let events = ds.dispatch_device_events().unwrap();
+		assert_eq!(2, events.len());
+		assert_eq!(vec![DeviceEventKind::PaperJam, DeviceEventKind::DeviceReady], *seen.lock());
+		assert_eq!(2, ds.device_event_subscribers.read().len());
+	}
+
+	#[test]
+	fn converts_tw_deviceevent() {
+		let tw_event = TW_DEVICEEVENT {
+			Event: TWDE_PAPERJAM,
+			DeviceName: tw_str255("Front feeder"),
+			BatteryMinutes: 0,
+			BatteryPercentage: 0,
+			PowerSupply: 0,
+			XResolution: f64_to_fix32(300.0),
+			YResolution: f64_to_fix32(300.0),
+			FlashUsed2: 0,
+			AutomaticCapture: 0,
+			TimeBeforeFirstCapture: 0,
+			TimeBetweenCaptures: 0,
+		};
+
+		let event = DeviceEvent::from(tw_event);
+		assert_eq!(DeviceEventKind::PaperJam, event.kind);
+		assert_eq!(300.0, event.x_resolution);
+		assert_eq!("PaperJam on \"Front feeder\"", event.to_string());
+	}
+}
@@ -0,0 +1,225 @@
+use super::DSMEntryWrapper;
+
+use std::cmp::Ordering;
+use std::env;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DSM_PATH_ENV: &str = "TWAIN_DSM_PATH";
+
+const DSM_FILE_NAMES: [&str; 2] = ["libtwaindsm.so.2", "libtwaindsm.so"];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CandidateOrigin {
+	// A path the application was given, not searched for
+	Explicit,
+	EnvOverride,
+	LdLibraryPath,
+	System,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DSMCandidate {
+	pub path: PathBuf,
+	pub resolved_path: PathBuf,
+	pub version: Option<Vec<u32>>,
+	pub origin: CandidateOrigin,
+}
+
+fn multiarch_triple() -> Option<&'static str> {
+	match env::consts::ARCH {
+		"x86_64"  => Some("x86_64-linux-gnu"),
+		"x86"     => Some("i386-linux-gnu"),
+		"aarch64" => Some("aarch64-linux-gnu"),
+		"arm"     => Some("arm-linux-gnueabihf"),
+		_ => None,
+	}
+}
+
+pub fn system_search_dirs() -> Vec<PathBuf> {
+	let mut dirs = vec![PathBuf::from("/usr/local/lib")];
+	if let Some(triple) = multiarch_triple() {
+		dirs.push(Path::new("/usr/local/lib").join(triple));
+	}
+	dirs.push(PathBuf::from("/usr/local/lib64"));
+	dirs.push(PathBuf::from("/usr/lib"));
+	if let Some(triple) = multiarch_triple() {
+		dirs.push(Path::new("/usr/lib").join(triple));
+		dirs.push(Path::new("/lib").join(triple));
+	}
+	dirs.push(PathBuf::from("/usr/lib64"));
+	dirs.push(PathBuf::from("/lib"));
+	dirs
+}
+
+// Parses "libtwaindsm.so.2.4.3" into [2, 4, 3]
+pub fn version_from_file_name(name: &OsStr) -> Option<Vec<u32>> {
+	let name = name.to_str()?;
+	let suffix = name.split_once(".so.")?.1;
+	suffix.split('.').map(|n| n.parse().ok()).collect()
+}
+
+fn candidate(path: PathBuf, origin: CandidateOrigin) -> Option<DSMCandidate> {
+	if !path.is_file() {
+		return None;
+	}
+
+	let resolved_path = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+	let version = resolved_path.file_name().and_then(version_from_file_name)
+		.or_else(|| path.file_name().and_then(version_from_file_name));
+
+	Some(DSMCandidate { path, resolved_path, version, origin })
+}
+
+fn candidates_in_dir(dir: &Path, origin: CandidateOrigin) -> Vec<DSMCandidate> {
+	DSM_FILE_NAMES.iter().filter_map(|name| candidate(dir.join(name), origin)).collect()
+}
+
+pub fn find_candidates_in<I: IntoIterator<Item = (PathBuf, CandidateOrigin)>>(dirs: I) -> Vec<DSMCandidate> {
+	let mut candidates: Vec<DSMCandidate> = Vec::new();
+
+	for (dir, origin) in dirs {
+		for c in candidates_in_dir(&dir, origin) {
+			if !candidates.iter().any(|existing| existing.resolved_path == c.resolved_path) {
+				candidates.push(c);
+			}
+		}
+	}
+
+	// Stable sort keeps search order among equally ranked candidates
+	candidates.sort_by(|a, b| a.rank_cmp(b));
+	candidates
+}
+
+pub fn explicit_candidate(path: &Path) -> Option<DSMCandidate> {
+	candidate(path.to_path_buf(), CandidateOrigin::Explicit)
+}
+
+pub fn find_dsm_candidates() -> Vec<DSMCandidate> {
+	let mut candidates = Vec::new();
+
+	if let Some(override_path) = env::var_os(DSM_PATH_ENV).map(PathBuf::from) {
+		if override_path.is_dir() {
+			candidates.extend(candidates_in_dir(&override_path, CandidateOrigin::EnvOverride));
+		} else if let Some(c) = candidate(override_path.clone(), CandidateOrigin::EnvOverride) {
+			candidates.push(c);
+		} else {
+			log::warn!("{} is set to \"{}\", which does not exist", DSM_PATH_ENV, override_path.display());
+		}
+	}
+
+	let mut dirs = Vec::new();
+	if let Some(ld_library_path) = env::var_os("LD_LIBRARY_PATH") {
+		dirs.extend(env::split_paths(&ld_library_path).filter(|p| !p.as_os_str().is_empty()).map(|p| (p, CandidateOrigin::LdLibraryPath)));
+	}
+	dirs.extend(system_search_dirs().into_iter().map(|p| (p, CandidateOrigin::System)));
+
+	for c in find_candidates_in(dirs) {
+		if !candidates.iter().any(|existing: &DSMCandidate| existing.resolved_path == c.resolved_path) {
+			candidates.push(c);
+		}
+	}
+
+	candidates
+}
+
+pub fn load_best_dsm() -> Option<(DSMCandidate, DSMEntryWrapper)> {
+	for c in find_dsm_candidates() {
+		log::debug!("Trying TWAIN DSM candidate {}", c);
+		match DSMEntryWrapper::from_path(&c.path) {
+			Ok(wrapper) => return Some((c, wrapper)),
+			Err(err) => log::warn!("Unable to load TWAIN DSM candidate {}: {}", c, err),
+		}
+	}
+	None
+}
+
+impl DSMCandidate {
+	pub fn version_string(&self) -> Option<String> {
+		self.version.as_ref().map(|v| v.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("."))
+	}
+
+	fn rank_cmp(&self, other: &Self) -> Ordering {
+		self.origin.cmp(&other.origin)
+			.then_with(|| other.version.cmp(&self.version))
+	}
+}
+
+impl fmt::Display for CandidateOrigin {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Explicit      => write!(f, "explicit path"),
+			Self::EnvOverride   => write!(f, "{}", DSM_PATH_ENV),
+			Self::LdLibraryPath => write!(f, "LD_LIBRARY_PATH"),
+			Self::System        => write!(f, "system library directory"),
+		}
+	}
+}
+
+impl fmt::Display for DSMCandidate {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self.version_string() {
+			Some(version) => write!(f, "\"{}\" (version {})", self.path.display(), version),
+			None => write!(f, "\"{}\"", self.path.display()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_versions_from_file_names() {
+		assert_eq!(Some(vec![2, 4, 3]), version_from_file_name(OsStr::new("libtwaindsm.so.2.4.3")));
+		assert_eq!(Some(vec![2]), version_from_file_name(OsStr::new("libtwaindsm.so.2")));
+		assert_eq!(None, version_from_file_name(OsStr::new("libtwaindsm.so")));
+		assert_eq!(None, version_from_file_name(OsStr::new("libtwaindsm.so.2.x")));
+	}
+
+	#[test]
+	fn ranks_candidates_by_origin_then_version() {
+		let root = env::temp_dir().join(format!("twain2-discovery-{}", std::process::id()));
+		let (old, new, ld) = (root.join("old"), root.join("new"), root.join("ld"));
+		for dir in [&old, &new, &ld] {
+			fs::create_dir_all(dir).unwrap();
+		}
+		fs::write(old.join("libtwaindsm.so.2"), b"").unwrap();
+		fs::write(new.join("libtwaindsm.so.2"), b"").unwrap();
+		fs::write(new.join("libtwaindsm.so"), b"").unwrap();
+		fs::write(ld.join("libtwaindsm.so"), b"").unwrap();
+
+		let candidates = find_candidates_in([
+			(old.clone(), CandidateOrigin::System),
+			(new.clone(), CandidateOrigin::System),
+			(ld.clone(), CandidateOrigin::LdLibraryPath),
+		]);
+
+		let paths: Vec<PathBuf> = candidates.iter().map(|c| c.path.clone()).collect();
+		assert_eq!(vec![
+			ld.join("libtwaindsm.so"),
+			old.join("libtwaindsm.so.2"),
+			new.join("libtwaindsm.so.2"),
+			new.join("libtwaindsm.so"),
+		], paths);
+
+		fs::remove_dir_all(&root).unwrap();
+	}
+
+	#[test]
+	fn explicit_candidate_reads_version_through_links() {
+		let root = env::temp_dir().join(format!("twain2-explicit-{}", std::process::id()));
+		fs::create_dir_all(&root).unwrap();
+		fs::write(root.join("libtwaindsm.so.2.4.3"), b"").unwrap();
+		std::os::unix::fs::symlink("libtwaindsm.so.2.4.3", root.join("libtwaindsm.so")).unwrap();
+
+		let candidate = explicit_candidate(&root.join("libtwaindsm.so")).unwrap();
+		assert_eq!(Some("2.4.3".to_string()), candidate.version_string());
+		assert_eq!(CandidateOrigin::Explicit, candidate.origin);
+		assert_eq!(None, explicit_candidate(&root.join("missing.so")));
+
+		fs::remove_dir_all(&root).unwrap();
+	}
+}
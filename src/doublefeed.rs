@@ -0,0 +1,182 @@
+use super::{DSError, DSState, OpenedDS};
+use super::capability::*;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DoubleFeedMethod {
+	Ultrasonic,
+	ByLength,
+	Infrared,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DoubleFeedSensitivity {
+	Low,
+	Medium,
+	High,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DoubleFeedResponse {
+	Stop,
+	StopAndWait,
+	Sound,
+	// Skip the imprinter / image signature for the double fed sheet
+	DoNotImprint,
+}
+
+// No methods means double-feed detection is off
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DoubleFeedConfig {
+	pub methods: Vec<DoubleFeedMethod>,
+	// Only used by DoubleFeedMethod::ByLength, in the current ICAP_UNITS
+	pub length: Option<f64>,
+	// Only used by DoubleFeedMethod::Ultrasonic
+	pub sensitivity: Option<DoubleFeedSensitivity>,
+	pub responses: Vec<DoubleFeedResponse>,
+}
+
+impl DoubleFeedMethod {
+	pub fn from_twdf(value: TW_UINT16) -> Option<Self> {
+		Some(match value as TwainUConst {
+			TWDF_ULTRASONIC => Self::Ultrasonic,
+			TWDF_BYLENGTH   => Self::ByLength,
+			TWDF_INFRARED   => Self::Infrared,
+			_ => return None,
+		})
+	}
+
+	pub fn to_twdf(&self) -> TW_UINT16 {
+		(match self {
+			Self::Ultrasonic => TWDF_ULTRASONIC,
+			Self::ByLength   => TWDF_BYLENGTH,
+			Self::Infrared   => TWDF_INFRARED,
+		}) as TW_UINT16
+	}
+}
+
+impl DoubleFeedSensitivity {
+	pub fn from_twus(value: TW_UINT16) -> Option<Self> {
+		Some(match value as TwainUConst {
+			TWUS_LOW    => Self::Low,
+			TWUS_MEDIUM => Self::Medium,
+			TWUS_HIGH   => Self::High,
+			_ => return None,
+		})
+	}
+
+	pub fn to_twus(&self) -> TW_UINT16 {
+		(match self {
+			Self::Low    => TWUS_LOW,
+			Self::Medium => TWUS_MEDIUM,
+			Self::High   => TWUS_HIGH,
+		}) as TW_UINT16
+	}
+}
+
+impl DoubleFeedResponse {
+	pub fn from_twdp(value: TW_UINT16) -> Option<Self> {
+		Some(match value as TwainUConst {
+			TWDP_STOP         => Self::Stop,
+			TWDP_STOPANDWAIT  => Self::StopAndWait,
+			TWDP_SOUND        => Self::Sound,
+			TWDP_DONOTIMPRINT => Self::DoNotImprint,
+			_ => return None,
+		})
+	}
+
+	pub fn to_twdp(&self) -> TW_UINT16 {
+		(match self {
+			Self::Stop         => TWDP_STOP,
+			Self::StopAndWait  => TWDP_STOPANDWAIT,
+			Self::Sound        => TWDP_SOUND,
+			Self::DoNotImprint => TWDP_DONOTIMPRINT,
+		}) as TW_UINT16
+	}
+}
+
+fn u16_values(container: &CapContainer) -> Vec<TW_UINT16> {
+	container.values().iter().filter_map(|v| v.as_u32()).map(|v| v as TW_UINT16).collect()
+}
+
+fn u16_array(values: impl Iterator<Item = TW_UINT16>) -> CapContainer {
+	CapContainer::Array(values.map(CapValue::UInt16).collect())
+}
+
+impl OpenedDS {
+	pub fn supported_double_feed_methods(&self) -> Result<Vec<DoubleFeedMethod>, DSError> {
+		let container = self.get_capability(CAP_DOUBLEFEEDDETECTION)?;
+		Ok(u16_values(&container).into_iter().filter_map(DoubleFeedMethod::from_twdf).collect())
+	}
+
+	pub fn supported_double_feed_responses(&self) -> Result<Vec<DoubleFeedResponse>, DSError> {
+		let container = self.get_capability(CAP_DOUBLEFEEDDETECTIONRESPONSE)?;
+		Ok(u16_values(&container).into_iter().filter_map(DoubleFeedResponse::from_twdp).collect())
+	}
+
+	pub fn double_feed_config(&self) -> Result<DoubleFeedConfig, DSError> {
+		let methods = self.get_capability_current(CAP_DOUBLEFEEDDETECTION)?;
+		let methods: Vec<DoubleFeedMethod> = u16_values(&methods).into_iter().filter_map(DoubleFeedMethod::from_twdf).collect();
+		if methods.is_empty() {
+			return Ok(DoubleFeedConfig::default());
+		}
+
+		let current = |cap| self.get_capability_current(cap).ok().and_then(|c| c.current().cloned());
+
+		Ok(DoubleFeedConfig {
+			length: current(CAP_DOUBLEFEEDDETECTIONLENGTH).and_then(|v| v.as_f64()),
+			sensitivity: current(CAP_DOUBLEFEEDDETECTIONSENSITIVITY).and_then(|v| v.as_u32()).and_then(|v| DoubleFeedSensitivity::from_twus(v as TW_UINT16)),
+			responses: self.get_capability_current(CAP_DOUBLEFEEDDETECTIONRESPONSE)
+				.map(|c| u16_values(&c).into_iter().filter_map(DoubleFeedResponse::from_twdp).collect())
+				.unwrap_or_default(),
+			methods,
+		})
+	}
+
+	// Returns the configuration read back from the source, which may have coerced some of the values
+	pub fn apply_double_feed_config(&self, config: &DoubleFeedConfig) -> Result<DoubleFeedConfig, DSError> {
+		if self.get_state() != DSState::SourceOpen {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		// The detection method goes first, the other double-feed capabilities depend on it
+		self.set_capability(CAP_DOUBLEFEEDDETECTION, &u16_array(config.methods.iter().map(|m| m.to_twdf())))?;
+
+		if !config.methods.is_empty() {
+			if let Some(length) = config.length {
+				self.set_capability_value(CAP_DOUBLEFEEDDETECTIONLENGTH, CapValue::Fix32(length))?;
+			}
+			if let Some(sensitivity) = config.sensitivity {
+				self.set_capability_value(CAP_DOUBLEFEEDDETECTIONSENSITIVITY, CapValue::UInt16(sensitivity.to_twus()))?;
+			}
+			if !config.responses.is_empty() {
+				self.set_capability(CAP_DOUBLEFEEDDETECTIONRESPONSE, &u16_array(config.responses.iter().map(|r| r.to_twdp())))?;
+			}
+		}
+
+		let applied = self.double_feed_config()?;
+		log::debug!("Applied double-feed configuration on \"{}\": {:?}", self.name, applied);
+		Ok(applied)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn double_feed_value_round_trip() {
+		for method in [DoubleFeedMethod::Ultrasonic, DoubleFeedMethod::ByLength, DoubleFeedMethod::Infrared] {
+			assert_eq!(Some(method), DoubleFeedMethod::from_twdf(method.to_twdf()));
+		}
+		for sensitivity in [DoubleFeedSensitivity::Low, DoubleFeedSensitivity::Medium, DoubleFeedSensitivity::High] {
+			assert_eq!(Some(sensitivity), DoubleFeedSensitivity::from_twus(sensitivity.to_twus()));
+		}
+		for response in [DoubleFeedResponse::Stop, DoubleFeedResponse::StopAndWait, DoubleFeedResponse::Sound, DoubleFeedResponse::DoNotImprint] {
+			assert_eq!(Some(response), DoubleFeedResponse::from_twdp(response.to_twdp()));
+		}
+		assert_eq!(None, DoubleFeedResponse::from_twdp(42));
+		assert_eq!(TWDP_STOPANDWAIT as TW_UINT16, DoubleFeedResponse::StopAndWait.to_twdp());
+	}
+}
@@ -0,0 +1,120 @@
+use super::blank::*;
+use super::extimageinfo::*;
+use super::image::Image;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sheet {
+	pub front: Image,
+	pub back: Option<Image>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PairOptions {
+	// Drops blank backs, None keeps all backs. Images that already carry a blank decision from
+	// the source's configured detector use it, the others are checked with this detector.
+	pub drop_blank_backs: Option<BlankDetector>,
+}
+
+pub struct Sheets<I> {
+	images: I,
+	options: PairOptions,
+	pending_front: Option<Image>,
+}
+
+pub fn pair_sheets<I: IntoIterator<Item = Image>>(images: I, options: PairOptions) -> Sheets<I::IntoIter> {
+	Sheets { images: images.into_iter(), options, pending_front: None }
+}
+
+impl<I: Iterator<Item = Image>> Sheets<I> {
+	fn sheet(&self, front: Image, back: Option<Image>) -> Sheet {
+		let back = back.filter(|back| match (self.options.drop_blank_backs, back.blank) {
+			(None, _) => true,
+			(Some(_), Some(decision)) => !decision.blank,
+			(Some(detector), None) => !detector.detect(back).blank,
+		});
+		Sheet { front, back }
+	}
+}
+
+impl<I: Iterator<Item = Image>> Iterator for Sheets<I> {
+	type Item = Sheet;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(image) = self.images.next() {
+			// Without TWEI_PAGESIDE sides are assumed to alternate, starting with a front
+			let is_back = match image.page_side() {
+				Some(side) => side as TwainUConst == TWCS_BOTTOM,
+				None => self.pending_front.is_some(),
+			};
+
+			match (self.pending_front.take(), is_back) {
+				(Some(front), true) => return Some(self.sheet(front, Some(image))),
+				(None, true) => {
+					log::warn!("Back side without a front, treating it as a single-sided sheet");
+					return Some(self.sheet(image, None));
+				},
+				(Some(front), false) => {
+					self.pending_front = Some(image);
+					return Some(self.sheet(front, None));
+				},
+				(None, false) => self.pending_front = Some(image),
+			}
+		}
+
+		self.pending_front.take().map(|front| self.sheet(front, None))
+	}
+}
+
+impl Image {
+	pub fn page_side(&self) -> Option<TW_UINT16> {
+		let item = self.ext_info.get(&(TWEI_PAGESIDE as TweiId))?.first()?;
+		item.as_i64().map(|side| side as TW_UINT16)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::capability::CapValue;
+	use super::super::image::PixelFormat;
+
+	fn page(id: u8, side: Option<TwainUConst>) -> Image {
+		let mut image = Image::new(1, 1, PixelFormat::Gray8);
+		image.data[0] = id;
+		if let Some(side) = side {
+			image.ext_info.insert(TWEI_PAGESIDE as TweiId, ExtInfo::Items(vec![ExtInfoItem::Value(CapValue::UInt16(side as TW_UINT16))]));
+		}
+		image
+	}
+
+	fn ids(sheets: Sheets<std::vec::IntoIter<Image>>) -> Vec<(u8, Option<u8>)> {
+		sheets.map(|s| (s.front.data[0], s.back.map(|b| b.data[0]))).collect()
+	}
+
+	#[test]
+	fn pairs_by_page_side() {
+		let images = vec![page(1, Some(TWCS_TOP)), page(2, Some(TWCS_BOTTOM)), page(3, Some(TWCS_TOP)), page(4, Some(TWCS_TOP)), page(5, Some(TWCS_BOTTOM))];
+		assert_eq!(vec![(1, Some(2)), (3, None), (4, Some(5))], ids(pair_sheets(images, PairOptions::default())));
+	}
+
+	#[test]
+	fn alternates_without_page_side() {
+		let images = vec![page(1, None), page(2, None), page(3, None)];
+		assert_eq!(vec![(1, Some(2)), (3, None)], ids(pair_sheets(images, PairOptions::default())));
+	}
+
+	#[test]
+	fn drops_blank_backs() {
+		let images = vec![page(0, None), page(255, None), page(0, None), page(0, None)];
+		let options = PairOptions { drop_blank_backs: Some(BlankDetector { threshold: 0.01, margin: 0.0 }) };
+		assert_eq!(vec![(0, None), (0, Some(0))], ids(pair_sheets(images, options)));
+
+		// The decision made at acquisition wins over the fallback detector
+		let mut ink_back = page(0, None);
+		ink_back.blank = Some(BlankDecision { blank: true, ink_coverage: 0.0 });
+		let images = vec![page(0, None), ink_back];
+		assert_eq!(vec![(0, None)], ids(pair_sheets(images, options)));
+	}
+}
@@ -1,5 +1,9 @@
 use super::twain_h::{TW_ENTRYPOINT, TW_HANDLE, TW_MEMREF, TW_UINT32};
 
+use std::alloc::{self, Layout};
+use std::mem;
+use std::ptr;
+
 pub struct EntryPoints {
 	pub allocate: Box<dyn Fn(TW_UINT32) -> TW_HANDLE + Send + Sync>,
 	pub free:     Box<dyn Fn(TW_HANDLE) + Send + Sync>,
@@ -59,4 +63,59 @@ impl EntryPoints {
 
 	#[cfg(unix)]
 	pub fn os_default() -> Option<EntryPoints> { None }
+
+	pub fn host() -> EntryPoints {
+		EntryPoints {
+			allocate: Box::new(|size| unsafe { host_allocate(size) }),
+			free:     Box::new(|handle| unsafe { host_free(handle) }),
+			lock:     Box::new(|handle| unsafe { host_lock(handle) }),
+			unlock:   Box::new(|handle| unsafe { host_unlock(handle) }),
+		}
+	}
+}
+
+// Memory functions for backends that stand in for a DSM, like session replay
+const HOST_ALLOC_HEADER: usize = 16;
+
+fn host_layout(size: usize) -> Option<Layout> {
+	Layout::from_size_align(size + HOST_ALLOC_HEADER, HOST_ALLOC_HEADER).ok()
+}
+
+unsafe extern "C" fn host_allocate(size: TW_UINT32) -> TW_HANDLE {
+	let p = match host_layout(size as usize) {
+		Some(layout) => alloc::alloc_zeroed(layout),
+		None => return ptr::null_mut(),
+	};
+	if p.is_null() {
+		return ptr::null_mut();
+	}
+	ptr::write(p as *mut usize, size as usize);
+	p.add(HOST_ALLOC_HEADER) as TW_HANDLE
+}
+
+unsafe extern "C" fn host_free(handle: TW_HANDLE) {
+	if handle.is_null() {
+		return;
+	}
+	let p = (handle as *mut u8).sub(HOST_ALLOC_HEADER);
+	if let Some(layout) = host_layout(ptr::read(p as *const usize)) {
+		alloc::dealloc(p, layout);
+	}
+}
+
+unsafe extern "C" fn host_lock(handle: TW_HANDLE) -> TW_MEMREF {
+	handle
+}
+
+unsafe extern "C" fn host_unlock(_handle: TW_HANDLE) {}
+
+pub fn host_tw_entrypoint() -> TW_ENTRYPOINT {
+	TW_ENTRYPOINT {
+		Size: mem::size_of::<TW_ENTRYPOINT>() as TW_UINT32,
+		DSM_Entry: None,
+		DSM_MemAllocate: Some(host_allocate),
+		DSM_MemFree: Some(host_free),
+		DSM_MemLock: Some(host_lock),
+		DSM_MemUnlock: Some(host_unlock),
+	}
 }
@@ -0,0 +1,388 @@
+use super::{DSError, DSState, OpenedDS};
+use super::capability::*;
+use super::data::PointerFromHandle;
+use super::entrypoint::EntryPoints;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::fmt;
+use std::mem;
+use std::ptr;
+
+pub type TweiId = TW_UINT16;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExtInfoItem {
+	Value(CapValue),
+	Data(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExtInfo {
+	Items(Vec<ExtInfoItem>),
+	NotSupported,
+	NotAvailable,
+	Failed(TW_UINT16),
+}
+
+pub type ExtImageInfo = BTreeMap<TweiId, ExtInfo>;
+
+impl ExtInfoItem {
+	pub fn as_value(&self) -> Option<&CapValue> {
+		match self {
+			Self::Value(v) => Some(v),
+			Self::Data(_) => None,
+		}
+	}
+
+	pub fn as_i64(&self) -> Option<i64> {
+		self.as_value()?.as_i64()
+	}
+
+	pub fn as_f64(&self) -> Option<f64> {
+		self.as_value()?.as_f64()
+	}
+
+	pub fn to_string_lossy(&self) -> Option<String> {
+		match self {
+			Self::Value(v) => v.as_str().map(String::from),
+			Self::Data(bytes) => {
+				let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+				Some(String::from_utf8_lossy(&bytes[..len]).into_owned())
+			},
+		}
+	}
+}
+
+impl ExtInfo {
+	pub fn items(&self) -> &[ExtInfoItem] {
+		match self {
+			Self::Items(items) => items,
+			_ => &[],
+		}
+	}
+
+	pub fn first(&self) -> Option<&ExtInfoItem> {
+		self.items().first()
+	}
+
+	pub fn is_available(&self) -> bool {
+		matches!(self, Self::Items(_))
+	}
+}
+
+// Handle data carries no size, these entries tell us how long each item of another entry is
+fn length_info(id: TweiId) -> Option<TweiId> {
+	match id as TwainUConst {
+		TWEI_BARCODETEXT => Some(TWEI_BARCODETEXTLENGTH as TweiId),
+		TWEI_MAGDATA     => Some(TWEI_MAGDATALENGTH as TweiId),
+		_ => None,
+	}
+}
+
+unsafe fn read_handle_data(ep: &EntryPoints, handle: TW_HANDLE, len: Option<usize>) -> Option<Vec<u8>> {
+	if handle.is_null() {
+		return None;
+	}
+
+	let data = {
+		let p = PointerFromHandle::<u8>::new(ep, handle)?;
+		match len {
+			Some(len) => std::slice::from_raw_parts(*p, len).to_vec(),
+			None => CStr::from_ptr(*p as *const _).to_bytes().to_vec(),
+		}
+	};
+	(ep.free)(handle);
+
+	Some(data)
+}
+
+unsafe fn decode_handles(ep: &EntryPoints, info: &TW_INFO, lengths: &[usize]) -> Option<Vec<ExtInfoItem>> {
+	let num_items = info.NumItems as usize;
+	let item = info.Item as usize as TW_HANDLE;
+
+	let handles = if num_items == 1 {
+		vec![item]
+	} else {
+		let handles = {
+			let p = PointerFromHandle::<TW_HANDLE>::new(ep, item)?;
+			(0..num_items).map(|i| ptr::read_unaligned(p.add(i))).collect()
+		};
+		(ep.free)(item);
+		handles
+	};
+
+	handles.into_iter().enumerate()
+		.map(|(i, handle)| read_handle_data(ep, handle, lengths.get(i).copied()).map(ExtInfoItem::Data))
+		.collect()
+}
+
+unsafe fn decode_info(ep: &EntryPoints, info: &TW_INFO, lengths: &[usize]) -> ExtInfo {
+	let return_code = info.__bindgen_anon_1.ReturnCode;
+	match return_code as TwainUConst {
+		TWRC_SUCCESS => {},
+		TWRC_INFONOTSUPPORTED => return ExtInfo::NotSupported,
+		TWRC_DATANOTAVAILABLE => return ExtInfo::NotAvailable,
+		_ => return ExtInfo::Failed(return_code),
+	}
+
+	let (item_type, num_items) = (info.ItemType, info.NumItems as usize);
+	if num_items == 0 {
+		return ExtInfo::Items(Vec::new());
+	}
+
+	if item_type as TwainUConst == TWTY_HANDLE {
+		return decode_handles(ep, info, lengths).map_or(ExtInfo::Failed(TWRC_FAILURE as TW_UINT16), ExtInfo::Items);
+	}
+
+	let size = match item_size(item_type) {
+		Some(size) => size,
+		None => return ExtInfo::Failed(TWRC_FAILURE as TW_UINT16),
+	};
+
+	// Data that fits in Item is stored inline, anything larger is in a handle allocated by the source
+	let item = info.Item;
+	let bytes = if size * num_items <= mem::size_of::<TW_UINTPTR>() {
+		item.to_ne_bytes().to_vec()
+	} else {
+		match read_handle_data(ep, item as usize as TW_HANDLE, Some(size * num_items)) {
+			Some(bytes) => bytes,
+			None => return ExtInfo::Failed(TWRC_FAILURE as TW_UINT16),
+		}
+	};
+
+	let items: Option<Vec<_>> = bytes.chunks(size).take(num_items)
+		.map(|chunk| CapValue::from_bytes(item_type, chunk).map(ExtInfoItem::Value))
+		.collect();
+	items.map_or(ExtInfo::Failed(TWRC_FAILURE as TW_UINT16), ExtInfo::Items)
+}
+
+// Decodes the entries of a TW_EXTIMAGEINFO, freeing all source-allocated memory
+unsafe fn decode_infos(ep: &EntryPoints, infos: &[TW_INFO]) -> ExtImageInfo {
+	let mut result = ExtImageInfo::new();
+
+	// Decode length entries first so the handle data that depends on them can be sized
+	let (lengths, others): (Vec<&TW_INFO>, Vec<&TW_INFO>) = infos.iter()
+		.partition(|info| infos.iter().any(|other| length_info(other.InfoID) == Some(info.InfoID)));
+
+	for info in lengths.into_iter().chain(others) {
+		let item_lengths: Vec<usize> = length_info(info.InfoID)
+			.and_then(|id| result.get(&id))
+			.map(|lengths| lengths.items().iter().filter_map(|l| l.as_i64()).map(|l| l as usize).collect())
+			.unwrap_or_default();
+
+		result.insert(info.InfoID, decode_info(ep, info, &item_lengths));
+	}
+
+	result
+}
+
+impl OpenedDS {
+	pub fn ext_image_info(&self, ids: &[TweiId]) -> Result<ExtImageInfo, DSError> {
+		if self.get_state() != DSState::Transferring {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let ep = self.dsm.entry_points.as_ref().ok_or(DSError::MemoryError)?;
+
+		let mut request: Vec<TweiId> = ids.to_vec();
+		for id in ids {
+			if let Some(length_id) = length_info(*id) {
+				if !request.contains(&length_id) {
+					request.push(length_id);
+				}
+			}
+		}
+
+		// TW_EXTIMAGEINFO is a TW_UINT32 followed by a variable length array of TW_INFO
+		let infos_offset = mem::size_of::<TW_EXTIMAGEINFO>() - mem::size_of::<TW_INFO>();
+		let len = infos_offset + request.len() * mem::size_of::<TW_INFO>();
+		let mut buffer = vec![0u16; len.div_ceil(2)];
+		let base = buffer.as_mut_ptr() as *mut u8;
+
+		unsafe {
+			ptr::write_unaligned(base as *mut TW_UINT32, request.len() as TW_UINT32);
+			for (i, id) in request.iter().enumerate() {
+				let info = TW_INFO {
+					InfoID: *id,
+					ItemType: 0,
+					NumItems: 0,
+					__bindgen_anon_1: TW_INFO__bindgen_ty_1 { ReturnCode: TWRC_INFONOTSUPPORTED as TW_UINT16 },
+					Item: 0,
+				};
+				ptr::write_unaligned((base.add(infos_offset) as *mut TW_INFO).add(i), info);
+			}
+		}
+
+		let res = self.do_dsm_entry(DG_IMAGE, DAT_EXTIMAGEINFO, MSG_GET, base as _);
+		if !res.is_success() {
+			return Err(DSError::BadResponse(res));
+		}
+
+		let infos: Vec<TW_INFO> = (0..request.len())
+			.map(|i| unsafe { ptr::read_unaligned((base.add(infos_offset) as *const TW_INFO).add(i)) })
+			.collect();
+
+		let mut result = unsafe { decode_infos(ep, &infos) };
+		result.retain(|id, _| ids.contains(id));
+
+		log::debug!("Got {} extended image info entries on \"{}\"", result.len(), self.name);
+		Ok(result)
+	}
+
+	pub fn supported_ext_image_info(&self) -> Result<Vec<TweiId>, DSError> {
+		let container = self.get_capability(ICAP_SUPPORTEDEXTIMAGEINFO)?;
+		Ok(container.values().iter().filter_map(|v| v.as_u32()).map(|v| v as TweiId).collect())
+	}
+
+	pub fn enable_ext_image_info(&self, ids: &[TweiId]) -> Result<Vec<TweiId>, DSError> {
+		if self.get_state() != DSState::SourceOpen {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		// Sources that can't report ICAP_SUPPORTEDEXTIMAGEINFO get asked for everything
+		let enabled: Vec<TweiId> = match self.supported_ext_image_info() {
+			Ok(supported) => ids.iter().copied().filter(|id| supported.contains(id)).collect(),
+			Err(DSError::BadResponse(res)) => {
+				log::debug!("Unable to get supported extended image info on \"{}\": {}", self.name, res);
+				ids.to_vec()
+			},
+			Err(err) => return Err(err),
+		};
+
+		for id in ids.iter().filter(|id| !enabled.contains(id)) {
+			log::warn!("Extended image info {} not supported on \"{}\"", id, self.name);
+		}
+
+		self.set_capability_value(ICAP_EXTIMAGEINFO, CapValue::Bool(!enabled.is_empty()))?;
+		*self.ext_image_info_ids.write() = enabled.clone();
+
+		log::debug!("Enabled {} extended image info entries on \"{}\"", enabled.len(), self.name);
+		Ok(enabled)
+	}
+
+	pub fn add_ext_image_info(&self, ids: &[TweiId]) -> Result<Vec<TweiId>, DSError> {
+		let mut all = self.ext_image_info_ids.read().clone();
+		for id in ids {
+			if !all.contains(id) {
+				all.push(*id);
+			}
+		}
+		self.enable_ext_image_info(&all)
+	}
+
+	pub fn remove_ext_image_info(&self, ids: &[TweiId]) -> Result<Vec<TweiId>, DSError> {
+		let current = self.ext_image_info_ids.read().clone();
+		if !current.iter().any(|id| ids.contains(id)) {
+			return Ok(current);
+		}
+
+		let remaining: Vec<TweiId> = current.into_iter().filter(|id| !ids.contains(id)).collect();
+		self.enable_ext_image_info(&remaining)
+	}
+
+	pub fn attached_ext_image_info(&self) -> ExtImageInfo {
+		let ids = self.ext_image_info_ids.read().clone();
+		if ids.is_empty() {
+			return ExtImageInfo::new();
+		}
+
+		self.ext_image_info(&ids).unwrap_or_else(|err| {
+			log::warn!("Unable to get extended image info on \"{}\": {}", self.name, err);
+			ExtImageInfo::new()
+		})
+	}
+}
+
+impl fmt::Display for ExtInfoItem {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Value(v) => write!(f, "{}", v),
+			Self::Data(_) => write!(f, "\"{}\"", self.to_string_lossy().unwrap_or_default()),
+		}
+	}
+}
+
+impl fmt::Display for ExtInfo {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Items(items) => {
+				let items: Vec<String> = items.iter().map(|i| i.to_string()).collect();
+				write!(f, "[{}]", items.join(", "))
+			},
+			Self::NotSupported => write!(f, "NotSupported"),
+			Self::NotAvailable => write!(f, "NotAvailable"),
+			Self::Failed(rc) => write!(f, "Failed({})", rc),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn alloc_bytes(ep: &EntryPoints, bytes: &[u8]) -> TW_HANDLE {
+		let handle = (ep.allocate)(bytes.len() as TW_UINT32);
+		unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), handle as *mut u8, bytes.len()) };
+		handle
+	}
+
+	fn info(id: TwainUConst, item_type: TwainUConst, num_items: usize, return_code: TwainUConst, item: TW_UINTPTR) -> TW_INFO {
+		TW_INFO {
+			InfoID: id as TW_UINT16,
+			ItemType: item_type as TW_UINT16,
+			NumItems: num_items as TW_UINT16,
+			__bindgen_anon_1: TW_INFO__bindgen_ty_1 { ReturnCode: return_code as TW_UINT16 },
+			Item: item,
+		}
+	}
+
+	#[test]
+	fn decodes_inline_and_handle_values() {
+		let ep = EntryPoints::host();
+
+		let mut inline = [0u8; mem::size_of::<TW_UINTPTR>()];
+		inline[..2].copy_from_slice(&(TWCS_TOP as u16).to_ne_bytes());
+		let counts: Vec<u8> = [7u32, 9, 11].iter().flat_map(|n| n.to_ne_bytes()).collect();
+		let counts_handle = alloc_bytes(&ep, &counts);
+
+		let infos = [
+			info(TWEI_PAGESIDE, TWTY_UINT16, 1, TWRC_SUCCESS, TW_UINTPTR::from_ne_bytes(inline)),
+			info(TWEI_BARCODEX, TWTY_UINT32, 3, TWRC_SUCCESS, counts_handle as usize as TW_UINTPTR),
+			info(TWEI_DESKEWSTATUS, 0, 0, TWRC_INFONOTSUPPORTED, 0),
+			info(TWEI_BOOKNAME, 0, 0, TWRC_DATANOTAVAILABLE, 0),
+		];
+
+		let result = unsafe { decode_infos(&ep, &infos) };
+
+		assert_eq!(ExtInfo::Items(vec![ExtInfoItem::Value(CapValue::UInt16(TWCS_TOP as u16))]), result[&(TWEI_PAGESIDE as TweiId)]);
+		assert_eq!(vec![7, 9, 11], result[&(TWEI_BARCODEX as TweiId)].items().iter().filter_map(|i| i.as_i64()).collect::<Vec<_>>());
+		assert_eq!(ExtInfo::NotSupported, result[&(TWEI_DESKEWSTATUS as TweiId)]);
+		assert_eq!(ExtInfo::NotAvailable, result[&(TWEI_BOOKNAME as TweiId)]);
+	}
+
+	#[test]
+	fn decodes_handle_arrays_using_lengths() {
+		let ep = EntryPoints::host();
+
+		let texts = [alloc_bytes(&ep, b"ABC-123"), alloc_bytes(&ep, b"XYZ\0")];
+		let handles: Vec<u8> = texts.iter().flat_map(|h| (*h as usize).to_ne_bytes()).collect();
+		let handles_handle = alloc_bytes(&ep, &handles);
+
+		let mut lengths = [0u8; mem::size_of::<TW_UINTPTR>()];
+		lengths[..4].copy_from_slice(&7u32.to_ne_bytes());
+		lengths[4..8].copy_from_slice(&3u32.to_ne_bytes());
+
+		let infos = [
+			info(TWEI_BARCODETEXT, TWTY_HANDLE, 2, TWRC_SUCCESS, handles_handle as usize as TW_UINTPTR),
+			info(TWEI_BARCODETEXTLENGTH, TWTY_UINT32, 2, TWRC_SUCCESS, TW_UINTPTR::from_ne_bytes(lengths)),
+		];
+
+		let result = unsafe { decode_infos(&ep, &infos) };
+		let text: Vec<String> = result[&(TWEI_BARCODETEXT as TweiId)].items().iter().filter_map(|i| i.to_string_lossy()).collect();
+
+		assert_eq!(vec!["ABC-123", "XYZ"], text);
+	}
+}
@@ -0,0 +1,168 @@
+// A scriptable in-process DSM with a single source, for unit tests that need an OpenedDS without libtwaindsm
+use super::{DSMEntryWrapper, OpenedDS, OpenedDSM, Triplet};
+use super::capability::*;
+use super::entrypoint::*;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::mem;
+use std::ptr;
+use std::sync::Arc;
+
+type CallbackProc = unsafe extern "C" fn(pTW_IDENTITY, pTW_IDENTITY, TW_UINT32, TW_UINT16, TW_UINT16, TW_MEMREF) -> TW_UINT16;
+
+#[derive(Default)]
+pub struct FakeSource {
+	// Every call except DAT_STATUS, in order
+	pub calls: Vec<Triplet>,
+	pub pages_per_batch: u16,
+	pub pending: u16,
+	// Capability values and whether they can be set, unknown capabilities fail with TWCC_CAPUNSUPPORTED
+	pub capabilities: HashMap<TW_UINT16, (CapContainer, bool)>,
+	// Capabilities in the order they were set
+	pub set_order: Vec<TW_UINT16>,
+	pub device_events: VecDeque<TW_DEVICEEVENT>,
+	callback: Option<(usize, TW_UINTPTR)>,
+	condition_code: TwainUConst,
+}
+
+impl FakeSource {
+	pub fn add_capability(&mut self, cap: TwainUConst, value: CapValue, settable: bool) {
+		self.capabilities.insert(cap as TW_UINT16, (CapContainer::OneValue(value), settable));
+	}
+
+	unsafe fn capability(&mut self, msg: TwainUConst, capability: &mut TW_CAPABILITY) -> TwainUConst {
+		let Some((container, settable)) = self.capabilities.get_mut(&capability.Cap) else {
+			self.condition_code = TWCC_CAPUNSUPPORTED;
+			return TWRC_FAILURE;
+		};
+
+		let reply = match msg {
+			MSG_GET | MSG_GETCURRENT | MSG_GETDEFAULT => container.clone(),
+			MSG_QUERYSUPPORT => {
+				let set = if *settable { TWQC_SET } else { 0 };
+				CapContainer::OneValue(CapValue::Int32((TWQC_GET | TWQC_GETCURRENT | TWQC_GETDEFAULT | set) as TW_INT32))
+			},
+			MSG_SET if *settable => {
+				let p = capability.hContainer as *const u8;
+				let header = std::slice::from_raw_parts(p, CONTAINER_LEN_HEADER_SIZE);
+				let len = CapContainer::len_from_header(capability.ConType, header).unwrap();
+				*container = CapContainer::from_bytes(capability.ConType, std::slice::from_raw_parts(p, len)).unwrap();
+				self.set_order.push(capability.Cap);
+				return TWRC_SUCCESS;
+			},
+			_ => {
+				self.condition_code = TWCC_CAPBADOPERATION;
+				return TWRC_FAILURE;
+			},
+		};
+
+		let bytes = reply.to_bytes();
+		let handle = (EntryPoints::host().allocate)(bytes.len() as TW_UINT32);
+		ptr::copy_nonoverlapping(bytes.as_ptr(), handle as *mut u8, bytes.len());
+		capability.ConType = reply.con_type();
+		capability.hContainer = handle;
+		TWRC_SUCCESS
+	}
+}
+
+// Pages are 1x1 white DIBs, pages_per_batch of them (one by default) every time the source is enabled
+pub fn fake_dsm() -> (DSMEntryWrapper, Arc<Mutex<FakeSource>>) {
+	let source = Arc::new(Mutex::new(FakeSource { pages_per_batch: 1, ..Default::default() }));
+	let fake = source.clone();
+
+	let wrapper = DSMEntryWrapper::from_fn(move |origin, dest, dg, dat, msg, data| unsafe {
+		if dat as TwainUConst == DAT_STATUS {
+			(*(data as pTW_STATUS)).ConditionCode = fake.lock().condition_code as TW_UINT16;
+			return TWRC_SUCCESS as TW_UINT16;
+		}
+
+		let mut source = fake.lock();
+		source.calls.push(Triplet::new(dg as TwainUConst, dat as TwainUConst, msg as TwainUConst));
+		source.condition_code = TWCC_SUCCESS;
+
+		let rc = match (dat as TwainUConst, msg as TwainUConst) {
+			// Like a real DSM, tell the application that TWAIN 2 entry points are available
+			(DAT_PARENT, MSG_OPENDSM) => {
+				(*origin).Id = 1;
+				(*origin).SupportedGroups |= DF_DSM2;
+				TWRC_SUCCESS
+			},
+			(DAT_ENTRYPOINT, MSG_GET) => {
+				ptr::write_unaligned(data as *mut TW_ENTRYPOINT, host_tw_entrypoint());
+				TWRC_SUCCESS
+			},
+			(DAT_CALLBACK2, MSG_REGISTER_CALLBACK) => {
+				let callback = ptr::read_unaligned(data as *const TW_CALLBACK2);
+				source.callback = Some((callback.CallBackProc as usize, callback.RefCon));
+				TWRC_SUCCESS
+			},
+			(DAT_CAPABILITY, msg) => {
+				let mut capability = ptr::read_unaligned(data as *const TW_CAPABILITY);
+				let rc = source.capability(msg, &mut capability);
+				ptr::write_unaligned(data as *mut TW_CAPABILITY, capability);
+				rc
+			},
+			(DAT_DEVICEEVENT, MSG_GET) => match source.device_events.pop_front() {
+				Some(event) => {
+					ptr::write_unaligned(data as *mut TW_DEVICEEVENT, event);
+					TWRC_SUCCESS
+				},
+				None => {
+					source.condition_code = TWCC_SEQERROR;
+					TWRC_FAILURE
+				},
+			},
+			(DAT_USERINTERFACE, MSG_ENABLEDS) => {
+				source.pending = source.pages_per_batch;
+				let callback = source.callback;
+				// The callback re-enters the application, which may call back into the DSM
+				drop(source);
+				if let Some((proc_address, ref_con)) = callback {
+					let proc: CallbackProc = mem::transmute(proc_address);
+					proc(dest, origin, DG_CONTROL, DAT_NULL as TW_UINT16, MSG_XFERREADY as TW_UINT16, ref_con as TW_MEMREF);
+				}
+				TWRC_SUCCESS
+			},
+			(DAT_IMAGENATIVEXFER, MSG_GET) => {
+				let mut dib = [0xffu8; 44];
+				dib[..40].fill(0);
+				dib[0] = 40;
+				dib[4] = 1;
+				dib[8] = 1;
+				dib[12] = 1;
+				dib[14] = 24;
+
+				let handle = (EntryPoints::host().allocate)(dib.len() as TW_UINT32);
+				ptr::copy_nonoverlapping(dib.as_ptr(), handle as *mut u8, dib.len());
+				ptr::write_unaligned(data as *mut TW_HANDLE, handle);
+				TWRC_XFERDONE
+			},
+			(DAT_PENDINGXFERS, msg) => {
+				source.pending = match msg {
+					MSG_ENDXFER => source.pending.saturating_sub(1),
+					MSG_RESET => 0,
+					_ => source.pending,
+				};
+				ptr::write_unaligned(data as *mut TW_PENDINGXFERS, TW_PENDINGXFERS { Count: source.pending, __bindgen_anon_1: TW_PENDINGXFERS__bindgen_ty_1 { EOJ: 0 } });
+				TWRC_SUCCESS
+			},
+			_ => TWRC_SUCCESS,
+		};
+		rc as TW_UINT16
+	});
+
+	(wrapper, source)
+}
+
+pub fn open_fake_source(wrapper: DSMEntryWrapper) -> Box<OpenedDS> {
+	let app_identity = TW_IDENTITY { SupportedGroups: DG_CONTROL | DG_IMAGE | DF_APP2, ..Default::default() };
+	let dsm = OpenedDSM::new(Arc::new(wrapper), app_identity).unwrap();
+	dsm.open_data_source(Default::default()).unwrap()
+}
+
+pub fn no_ui() -> TW_USERINTERFACE {
+	TW_USERINTERFACE { ShowUI: 0, ModalUI: 0, hParent: ptr::null_mut() }
+}
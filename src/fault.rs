@@ -0,0 +1,387 @@
+use super::{DSMEntryWrapper, Triplet};
+use super::entrypoint::EntryPoints;
+use super::response::*;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::mem;
+use std::ptr;
+use std::sync::Arc;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fault {
+	// Fail the call with TWRC_FAILURE and this condition code, without passing it on to the DSM
+	Failure(TW_UINT16),
+	// Swallow the next MSG_XFERREADY the source sends after this call
+	DropXferReady,
+	// Send the application a MSG_CLOSEDSREQ it didn't ask for after this call
+	CloseDSRequest,
+	// Replace the handle returned by a native transfer or capability call with an empty one
+	EmptyHandle,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FaultRule {
+	pub triplet: Triplet,
+	// Counted from 1, per triplet
+	pub nth: usize,
+	pub fault: Fault,
+}
+
+type CallbackProc = unsafe extern "C" fn(pTW_IDENTITY, pTW_IDENTITY, TW_UINT32, TW_UINT16, TW_UINT16, TW_MEMREF) -> TW_UINT16;
+
+#[derive(Default)]
+struct InjectorState {
+	rules: Vec<FaultRule>,
+	counts: HashMap<Triplet, usize>,
+	status: Option<Response>,
+	// CallBackProc and RefCon registered by the application, stored as integers to keep the state Send
+	callback: Option<(usize, TW_UINTPTR)>,
+	drop_xfer_ready: usize,
+	injected: Vec<(Triplet, Fault)>,
+}
+
+// Sits between the application and a real (or fake) DSM, passing calls through until a rule matches
+pub struct FaultInjector {
+	inner: DSMEntryWrapper,
+	entry_points: Mutex<Option<EntryPoints>>,
+	state: Mutex<InjectorState>,
+}
+
+impl FaultRule {
+	pub fn new(triplet: Triplet, nth: usize, fault: Fault) -> Self {
+		Self { triplet, nth, fault }
+	}
+}
+
+impl FaultInjector {
+	pub fn new(inner: DSMEntryWrapper) -> Arc<Self> {
+		Arc::new(Self { inner, entry_points: Mutex::new(EntryPoints::os_default()), state: Mutex::new(Default::default()) })
+	}
+
+	pub fn add_rule(&self, rule: FaultRule) {
+		self.state.lock().rules.push(rule);
+	}
+
+	pub fn fail_nth(&self, triplet: Triplet, nth: usize, condition_code: TwainUConst) {
+		self.add_rule(FaultRule::new(triplet, nth, Fault::Failure(condition_code as TW_UINT16)));
+	}
+
+	// Faults that have been injected so far, in order
+	pub fn injected(&self) -> Vec<(Triplet, Fault)> {
+		self.state.lock().injected.clone()
+	}
+
+	pub fn wrapper(self: &Arc<Self>) -> DSMEntryWrapper {
+		let injector = self.clone();
+		DSMEntryWrapper::from_fn(move |origin, dest, dg, dat, msg, data| {
+			unsafe { injector.entry(origin, dest, Triplet::new(dg as TwainUConst, dat as TwainUConst, msg as TwainUConst), data) }
+		})
+	}
+
+	fn next_faults(&self, triplet: Triplet) -> Vec<Fault> {
+		let mut state = self.state.lock();
+		let count = {
+			let count = state.counts.entry(triplet).or_insert(0);
+			*count += 1;
+			*count
+		};
+
+		let faults: Vec<Fault> = state.rules.iter().filter(|r| r.triplet == triplet && r.nth == count).map(|r| r.fault).collect();
+		state.injected.extend(faults.iter().map(|f| (triplet, *f)));
+		faults
+	}
+
+	unsafe fn entry(&self, origin: pTW_IDENTITY, dest: pTW_IDENTITY, triplet: Triplet, data: TW_MEMREF) -> TW_UINT16 {
+		// DSMEntryWrapper asks for the status right after every call, answer with what the inner wrapper got
+		if triplet == Triplet::new(DG_CONTROL, DAT_STATUS, MSG_GET) {
+			return match self.state.lock().status.take().and_then(|res| res.condition_code.to_cc()) {
+				Some(cc) => {
+					ptr::write_unaligned(ptr::addr_of_mut!((*(data as pTW_STATUS)).ConditionCode), cc);
+					TWRC_SUCCESS as TW_UINT16
+				},
+				None => TWRC_FAILURE as TW_UINT16,
+			};
+		}
+
+		let faults = self.next_faults(triplet);
+
+		if let Some(cc) = faults.iter().find_map(|f| match f { Fault::Failure(cc) => Some(*cc), _ => None }) {
+			log::debug!("Injecting failure {} into {}", ConditionCode::from_cc(cc), triplet);
+			self.state.lock().status = Some(Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::from_cc(cc) });
+			return TWRC_FAILURE as TW_UINT16;
+		}
+
+		// Sources may send MSG_XFERREADY before MSG_ENABLEDS returns
+		let drop_xfer_ready = faults.iter().filter(|f| **f == Fault::DropXferReady).count();
+		self.state.lock().drop_xfer_ready += drop_xfer_ready;
+
+		let res = if triplet == Triplet::new(DG_CONTROL, DAT_CALLBACK2, MSG_REGISTER_CALLBACK) && !data.is_null() {
+			// Register ourselves so callbacks can be filtered before they reach the application
+			let mut callback = ptr::read_unaligned(data as *const TW_CALLBACK2);
+			self.state.lock().callback = Some((callback.CallBackProc as usize, callback.RefCon));
+			callback.CallBackProc = Self::callback as CallbackProc as TW_MEMREF;
+			callback.RefCon = self as *const Self as TW_UINTPTR;
+			self.inner.do_dsm_entry_with_retry(None, origin.as_mut(), dest.as_mut(), triplet, &mut callback as *mut TW_CALLBACK2 as _)
+		} else {
+			self.inner.do_dsm_entry_with_retry(None, origin.as_mut(), dest.as_mut(), triplet, data)
+		};
+
+		if triplet == Triplet::new(DG_CONTROL, DAT_ENTRYPOINT, MSG_GET) && res.is_success() {
+			*self.entry_points.lock() = EntryPoints::from_tw_entrypoint(ptr::read_unaligned(data as *const TW_ENTRYPOINT));
+		}
+
+		for fault in faults {
+			match fault {
+				Fault::CloseDSRequest => self.send_callback(dest, origin, MSG_CLOSEDSREQ),
+				Fault::EmptyHandle => self.empty_handle(triplet, &res, data),
+				Fault::Failure(_) | Fault::DropXferReady => {},
+			}
+		}
+
+		let rc = res.return_code.to_rc();
+		self.state.lock().status = Some(res);
+		rc
+	}
+
+	unsafe fn empty_handle(&self, triplet: Triplet, res: &Response, data: TW_MEMREF) {
+		let ep = self.entry_points.lock();
+		let free = |handle: TW_HANDLE| if let (Some(ep), false) = (ep.as_ref(), handle.is_null()) { (ep.free)(handle) };
+
+		match triplet.dat {
+			DAT_IMAGENATIVEXFER if res.return_code == ReturnCode::XferDone => {
+				free(ptr::read_unaligned(data as *const TW_HANDLE));
+				ptr::write_unaligned(data as *mut TW_HANDLE, ptr::null_mut());
+			},
+			DAT_CAPABILITY if res.is_success() => {
+				let mut capability = ptr::read_unaligned(data as *const TW_CAPABILITY);
+				free(capability.hContainer);
+				capability.hContainer = ptr::null_mut();
+				ptr::write_unaligned(data as *mut TW_CAPABILITY, capability);
+			},
+			_ => log::warn!("No handle to empty in {}", triplet),
+		}
+	}
+
+	unsafe fn send_callback(&self, origin: pTW_IDENTITY, dest: pTW_IDENTITY, msg: TwainUConst) {
+		let callback = self.state.lock().callback;
+		let Some((proc_address, ref_con)) = callback else {
+			log::warn!("Unable to inject callback, none registered");
+			return;
+		};
+
+		// The application may not expect null identities in a callback
+		let mut origin_id = origin.as_ref().copied().unwrap_or_default();
+		let mut dest_id = dest.as_ref().copied().unwrap_or_default();
+
+		let proc: CallbackProc = mem::transmute(proc_address);
+		proc(&mut origin_id, &mut dest_id, DG_CONTROL, DAT_NULL as TW_UINT16, msg as TW_UINT16, ref_con as TW_MEMREF);
+	}
+
+	extern "C" fn callback(origin: pTW_IDENTITY, dest: pTW_IDENTITY, dg: TW_UINT32, dat: TW_UINT16, msg: TW_UINT16, data: TW_MEMREF) -> TW_UINT16 {
+		let self_ = unsafe { &*(data as *const Self) };
+
+		let callback = {
+			let mut state = self_.state.lock();
+			if msg as TwainUConst == MSG_XFERREADY && state.drop_xfer_ready > 0 {
+				state.drop_xfer_ready -= 1;
+				state.injected.push((Triplet::new(dg, dat as TwainUConst, msg as TwainUConst), Fault::DropXferReady));
+				log::debug!("Dropping MSG_XFERREADY");
+				return TWRC_SUCCESS as TW_UINT16;
+			}
+			state.callback
+		};
+
+		match callback {
+			Some((proc_address, ref_con)) => unsafe {
+				let proc: CallbackProc = mem::transmute(proc_address);
+				proc(origin, dest, dg, dat, msg, ref_con as TW_MEMREF)
+			},
+			None => TWRC_FAILURE as TW_UINT16,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::{DSError, DSState, OpenedDS};
+	use super::super::fake::*;
+	use super::super::recovery::*;
+	use std::time::Duration;
+
+	fn open_source(injector: &Arc<FaultInjector>) -> Box<OpenedDS> {
+		open_fake_source(injector.wrapper())
+	}
+
+	const ENABLEDS: Triplet = Triplet { dg: DG_CONTROL, dat: DAT_USERINTERFACE, msg: MSG_ENABLEDS };
+	const NATIVEXFER: Triplet = Triplet { dg: DG_IMAGE, dat: DAT_IMAGENATIVEXFER, msg: MSG_GET };
+	const ENDXFER: Triplet = Triplet { dg: DG_CONTROL, dat: DAT_PENDINGXFERS, msg: MSG_ENDXFER };
+
+	#[test]
+	fn enable_rolls_back_on_failure() {
+		let (fake, _) = fake_dsm();
+		let injector = FaultInjector::new(fake);
+		injector.fail_nth(ENABLEDS, 1, TWCC_BUMMER);
+		let ds = open_source(&injector);
+
+		let res = Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::Bummer };
+		assert_eq!(Err(DSError::BadResponse(res)), ds.enable(no_ui()));
+		assert_eq!(DSState::SourceOpen, ds.get_state());
+		assert!(ds.ui.read().is_none());
+
+		ds.enable(no_ui()).unwrap();
+		assert_eq!(DSState::TransferReady, ds.get_state());
+		assert_eq!(vec![(ENABLEDS, Fault::Failure(TWCC_BUMMER as TW_UINT16))], injector.injected());
+	}
+
+	#[test]
+	fn dropped_xfer_ready_leaves_source_enabled() {
+		let (fake, _) = fake_dsm();
+		let injector = FaultInjector::new(fake);
+		injector.add_rule(FaultRule::new(ENABLEDS, 1, Fault::DropXferReady));
+		let ds = open_source(&injector);
+
+		ds.enable(no_ui()).unwrap();
+		assert_eq!(DSState::SourceEnabled, ds.wait_while_state(DSState::SourceEnabled, Duration::from_millis(50)));
+		assert_eq!(Err(DSError::InvalidState(DSState::SourceEnabled)), ds.acquire_image().map(|_| ()));
+
+		ds.disable().unwrap();
+		assert_eq!(DSState::SourceOpen, ds.get_state());
+	}
+
+	#[test]
+	fn empty_handle_ends_the_transfer() {
+		let (fake, _) = fake_dsm();
+		let injector = FaultInjector::new(fake);
+		injector.add_rule(FaultRule::new(NATIVEXFER, 1, Fault::EmptyHandle));
+		let ds = open_source(&injector);
+
+		ds.enable(no_ui()).unwrap();
+		assert_eq!(Err(DSError::MemoryError), ds.acquire_image().map(|_| ()));
+		assert_eq!(DSState::SourceEnabled, ds.get_state());
+	}
+
+	#[test]
+	fn spurious_close_request_is_reported() {
+		let (fake, source) = fake_dsm();
+		source.lock().pages_per_batch = 2;
+		let injector = FaultInjector::new(fake);
+		injector.add_rule(FaultRule::new(NATIVEXFER, 1, Fault::CloseDSRequest));
+		let ds = open_source(&injector);
+
+		ds.enable(no_ui()).unwrap();
+		assert!(!ds.close_requested());
+		assert!(ds.acquire_image().unwrap().is_some());
+		assert!(ds.close_requested());
+		assert_eq!(DSState::TransferReady, ds.get_state());
+
+		ds.end_faulted_transfer().unwrap();
+		ds.disable().unwrap();
+		ds.enable(no_ui()).unwrap();
+		assert!(!ds.close_requested());
+	}
+
+	#[test]
+	fn failed_end_transfer_keeps_the_image() {
+		let (fake, _) = fake_dsm();
+		let injector = FaultInjector::new(fake);
+		injector.fail_nth(ENDXFER, 1, TWCC_OPERATIONERROR);
+		let ds = open_source(&injector);
+
+		ds.enable(no_ui()).unwrap();
+		let image = ds.acquire_image().unwrap().unwrap();
+		assert_eq!((1, 1), (image.width, image.height));
+		assert_eq!(DSState::Transferring, ds.get_state());
+		assert_eq!(None, ds.last_transfer());
+
+		let outcome = ds.end_faulted_transfer().unwrap().unwrap();
+		assert!(outcome.finished);
+		assert_eq!(DSState::SourceEnabled, ds.get_state());
+	}
+
+	#[test]
+	fn failed_end_transfer_is_ended_on_close() {
+		let (fake, source) = fake_dsm();
+		source.lock().pages_per_batch = 2;
+		let injector = FaultInjector::new(fake);
+		injector.fail_nth(ENDXFER, 1, TWCC_OPERATIONERROR);
+		let ds = open_source(&injector);
+
+		ds.enable(no_ui()).unwrap();
+		assert!(ds.acquire_image().unwrap().is_some());
+		assert_eq!(DSState::Transferring, ds.get_state());
+		drop(ds);
+
+		let calls = source.lock().calls.clone();
+		let close = calls.iter().position(|t| t.msg == MSG_CLOSEDS).unwrap();
+		let tail: Vec<TwainUConst> = calls[close - 3..=close].iter().map(|t| t.msg).collect();
+		assert_eq!(vec![MSG_ENDXFER, MSG_RESET, MSG_DISABLEDS, MSG_CLOSEDS], tail);
+	}
+
+	#[test]
+	fn fault_past_resume_limit_is_reported() {
+		let (fake, source) = fake_dsm();
+		source.lock().pages_per_batch = 3;
+		let injector = FaultInjector::new(fake);
+		injector.fail_nth(NATIVEXFER, 2, TWCC_PAPERJAM);
+		injector.fail_nth(NATIVEXFER, 4, TWCC_PAPERJAM);
+		let ds = open_source(&injector);
+		let options = RecoveryOptions { max_resumes: 1, ready_timeout: Duration::from_millis(50) };
+
+		let mut events = Vec::new();
+		ds.enable(no_ui()).unwrap();
+		// The first page is dropped as if cancelled, the one after the resume is delivered
+		let mut calls = 0;
+		let batch = ds.acquire_with_recovery(&options, |ds| {
+			calls += 1;
+			let image = ds.acquire_image()?;
+			Ok(image.filter(|_| calls > 1))
+		}, |event| {
+			events.push(event.page_index);
+			RecoveryAction::Resume
+		});
+
+		assert!(batch.aborted);
+		assert!(batch.error.is_none());
+		assert_eq!(1, batch.pages.len());
+		assert_eq!(vec![0, 1], events);
+	}
+
+	#[test]
+	fn recovery_keeps_pages_acquired_before_an_error() {
+		let (fake, source) = fake_dsm();
+		source.lock().pages_per_batch = 3;
+		let injector = FaultInjector::new(fake);
+		injector.fail_nth(NATIVEXFER, 2, TWCC_OPERATIONERROR);
+		let ds = open_source(&injector);
+
+		ds.enable(no_ui()).unwrap();
+		let batch = ds.acquire_with_recovery(&Default::default(), |ds| ds.acquire_image(), |_| RecoveryAction::Resume);
+
+		let res = Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::OperationError };
+		assert!(batch.aborted);
+		assert_eq!(Some(DSError::BadResponse(res)), batch.error);
+		assert_eq!(1, batch.pages.len());
+	}
+
+	#[test]
+	fn recovery_ends_a_transfer_the_source_failed_to_end() {
+		let (fake, source) = fake_dsm();
+		source.lock().pages_per_batch = 3;
+		let injector = FaultInjector::new(fake);
+		injector.fail_nth(ENDXFER, 2, TWCC_OPERATIONERROR);
+		let ds = open_source(&injector);
+
+		ds.enable(no_ui()).unwrap();
+		let batch = ds.acquire_with_recovery(&Default::default(), |ds| ds.acquire_image(), |_| RecoveryAction::Resume);
+
+		assert!(batch.aborted);
+		assert_eq!(Some(DSError::InvalidState(DSState::Transferring)), batch.error);
+		assert_eq!(2, batch.pages.len());
+		assert!(batch.faults.is_empty());
+		assert_eq!(DSState::SourceEnabled, ds.get_state());
+	}
+}
@@ -0,0 +1,210 @@
+use super::{DSError, DSState, OpenedDS};
+use super::capability::*;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeederConfig {
+	pub feeder_enabled: bool,
+	pub auto_feed: Option<bool>,
+	pub duplex_enabled: bool,
+	pub transfer_count: Option<TW_INT16>,
+	pub require_paper: bool,
+}
+
+// Values read back from the source after applying a FeederConfig, None where the source doesn't report them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeederSettings {
+	pub feeder_enabled: bool,
+	pub auto_feed: Option<bool>,
+	pub duplex: TW_UINT16,
+	pub duplex_enabled: bool,
+	pub transfer_count: Option<TW_INT16>,
+	pub paper_loaded: Option<bool>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FeederError {
+	Unsupported(TW_UINT16),
+	NoPaper,
+	Device(DSError),
+}
+
+impl Default for FeederConfig {
+	fn default() -> Self {
+		Self {
+			feeder_enabled: true,
+			auto_feed: None,
+			duplex_enabled: false,
+			transfer_count: None,
+			require_paper: true,
+		}
+	}
+}
+
+impl OpenedDS {
+	fn can_set_capability(&self, cap: TwainUConst) -> bool {
+		self.query_capability_support(cap).is_ok_and(|support| support.set())
+	}
+
+	fn current_bool(&self, cap: TwainUConst) -> Option<bool> {
+		self.get_capability_current(cap).ok()?.current()?.as_bool()
+	}
+
+	// None if the source can't detect paper in the feeder
+	pub fn feeder_loaded(&self) -> Result<Option<bool>, DSError> {
+		if self.current_bool(CAP_PAPERDETECTABLE) == Some(false) {
+			return Ok(None);
+		}
+
+		match self.get_capability_current(CAP_FEEDERLOADED) {
+			Ok(container) => Ok(container.current().and_then(|v| v.as_bool())),
+			Err(DSError::BadResponse(_)) => Ok(None),
+			Err(err) => Err(err),
+		}
+	}
+
+	pub fn duplex_support(&self) -> TW_UINT16 {
+		self.get_capability_current(CAP_DUPLEX).ok()
+			.and_then(|c| c.current().and_then(|v| v.as_u32()))
+			.map_or(TWDX_NONE as TW_UINT16, |v| v as TW_UINT16)
+	}
+
+	pub fn apply_feeder_config(&self, config: &FeederConfig) -> Result<FeederSettings, FeederError> {
+		if self.get_state() != DSState::SourceOpen {
+			return Err(FeederError::Device(DSError::InvalidState(self.get_state())));
+		}
+
+		// CAP_FEEDERENABLED goes first, the source only allows the other feeder capabilities once it is set
+		if config.feeder_enabled && !self.can_set_capability(CAP_FEEDERENABLED) {
+			return Err(FeederError::Unsupported(CAP_FEEDERENABLED as TW_UINT16));
+		}
+		if self.can_set_capability(CAP_FEEDERENABLED) {
+			self.set_capability_value(CAP_FEEDERENABLED, CapValue::Bool(config.feeder_enabled))?;
+		}
+
+		let duplex = self.duplex_support();
+		if config.duplex_enabled {
+			if duplex == TWDX_NONE as TW_UINT16 || !self.can_set_capability(CAP_DUPLEXENABLED) {
+				return Err(FeederError::Unsupported(CAP_DUPLEXENABLED as TW_UINT16));
+			}
+			self.set_capability_value(CAP_DUPLEXENABLED, CapValue::Bool(true))?;
+		} else if self.can_set_capability(CAP_DUPLEXENABLED) {
+			self.set_capability_value(CAP_DUPLEXENABLED, CapValue::Bool(false))?;
+		}
+
+		if let Some(auto_feed) = config.auto_feed {
+			if !self.can_set_capability(CAP_AUTOFEED) {
+				return Err(FeederError::Unsupported(CAP_AUTOFEED as TW_UINT16));
+			}
+			self.set_capability_value(CAP_AUTOFEED, CapValue::Bool(auto_feed))?;
+		}
+
+		if let Some(count) = config.transfer_count {
+			self.set_capability_value(CAP_XFERCOUNT, CapValue::Int16(count))?;
+		}
+
+		let settings = FeederSettings {
+			feeder_enabled: self.current_bool(CAP_FEEDERENABLED).unwrap_or(false),
+			auto_feed: self.current_bool(CAP_AUTOFEED),
+			duplex,
+			duplex_enabled: self.current_bool(CAP_DUPLEXENABLED).unwrap_or(false),
+			transfer_count: self.get_capability_current(CAP_XFERCOUNT).ok()
+				.and_then(|c| c.current().and_then(|v| v.as_i64()))
+				.map(|v| v as TW_INT16),
+			paper_loaded: if config.feeder_enabled { self.feeder_loaded()? } else { None },
+		};
+
+		log::debug!("Applied feeder configuration on \"{}\": {:?}", self.name, settings);
+
+		if config.feeder_enabled && config.require_paper && settings.paper_loaded == Some(false) {
+			return Err(FeederError::NoPaper);
+		}
+
+		Ok(settings)
+	}
+}
+
+impl From<DSError> for FeederError {
+	fn from(err: DSError) -> Self {
+		Self::Device(err)
+	}
+}
+
+impl fmt::Display for FeederError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Unsupported(cap) => write!(f, "Unsupported({})", cap_label(*cap)),
+			Self::NoPaper          => write!(f, "NoPaper"),
+			Self::Device(err)      => write!(f, "Device({})", err),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::fake::*;
+
+	use parking_lot::Mutex;
+	use std::sync::Arc;
+
+	fn feeder_source(duplex: TwainUConst, paper: bool) -> (Box<OpenedDS>, Arc<Mutex<FakeSource>>) {
+		let (wrapper, source) = fake_dsm();
+		{
+			let mut source = source.lock();
+			source.add_capability(CAP_FEEDERENABLED, CapValue::Bool(false), true);
+			source.add_capability(CAP_AUTOFEED, CapValue::Bool(false), true);
+			source.add_capability(CAP_DUPLEX, CapValue::UInt16(duplex as TW_UINT16), false);
+			source.add_capability(CAP_DUPLEXENABLED, CapValue::Bool(false), true);
+			source.add_capability(CAP_XFERCOUNT, CapValue::Int16(-1), true);
+			source.add_capability(CAP_PAPERDETECTABLE, CapValue::Bool(true), false);
+			source.add_capability(CAP_FEEDERLOADED, CapValue::Bool(paper), false);
+		}
+		(open_fake_source(wrapper), source)
+	}
+
+	#[test]
+	fn feeder_enabled_first_and_read_back() {
+		let (ds, source) = feeder_source(TWDX_2PASSDUPLEX, true);
+		let config = FeederConfig { auto_feed: Some(true), duplex_enabled: true, transfer_count: Some(4), ..Default::default() };
+
+		let settings = ds.apply_feeder_config(&config).unwrap();
+		assert_eq!(FeederSettings {
+			feeder_enabled: true,
+			auto_feed: Some(true),
+			duplex: TWDX_2PASSDUPLEX as TW_UINT16,
+			duplex_enabled: true,
+			transfer_count: Some(4),
+			paper_loaded: Some(true),
+		}, settings);
+
+		let order: Vec<TwainUConst> = source.lock().set_order.iter().map(|cap| *cap as TwainUConst).collect();
+		assert_eq!(vec![CAP_FEEDERENABLED, CAP_DUPLEXENABLED, CAP_AUTOFEED, CAP_XFERCOUNT], order);
+	}
+
+	#[test]
+	fn unsupported_feeder_settings() {
+		let (ds, source) = feeder_source(TWDX_NONE, true);
+		let duplex = FeederConfig { duplex_enabled: true, ..Default::default() };
+		assert_eq!(Err(FeederError::Unsupported(CAP_DUPLEXENABLED as TW_UINT16)), ds.apply_feeder_config(&duplex));
+
+		source.lock().capabilities.remove(&(CAP_FEEDERENABLED as TW_UINT16));
+		assert_eq!(Err(FeederError::Unsupported(CAP_FEEDERENABLED as TW_UINT16)), ds.apply_feeder_config(&Default::default()));
+
+		// Flatbed only, nothing to enable
+		let flatbed = FeederConfig { feeder_enabled: false, ..Default::default() };
+		assert!(!ds.apply_feeder_config(&flatbed).unwrap().feeder_enabled);
+	}
+
+	#[test]
+	fn empty_feeder() {
+		let (ds, _source) = feeder_source(TWDX_NONE, false);
+		assert_eq!(Err(FeederError::NoPaper), ds.apply_feeder_config(&Default::default()));
+
+		let config = FeederConfig { require_paper: false, ..Default::default() };
+		assert_eq!(Some(false), ds.apply_feeder_config(&config).unwrap().paper_loaded);
+	}
+}
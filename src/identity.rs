@@ -0,0 +1,155 @@
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionInfo {
+	pub major: TW_UINT16,
+	pub minor: TW_UINT16,
+	pub language: TW_UINT16,
+	pub country: TW_UINT16,
+	pub info: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SupportedGroups(pub TW_UINT32);
+
+#[derive(Debug, Clone)]
+pub struct DataSourceInfo {
+	pub id: TW_UINT32,
+	pub manufacturer: String,
+	pub product_family: String,
+	pub product_name: String,
+	pub version: VersionInfo,
+	pub protocol_major: TW_UINT16,
+	pub protocol_minor: TW_UINT16,
+	pub supported_groups: SupportedGroups,
+	pub identity: TW_IDENTITY,
+}
+
+impl VersionInfo {
+	pub fn from_tw_version(version: &TW_VERSION) -> Self {
+		Self {
+			major: version.MajorNum,
+			minor: version.MinorNum,
+			language: version.Language,
+			country: version.Country,
+			info: tw_str32_to_string(&version.Info),
+		}
+	}
+}
+
+impl SupportedGroups {
+	pub fn has(&self, flag: TwainUConst) -> bool {
+		self.0 & flag as TW_UINT32 != 0
+	}
+
+	pub fn control(&self) -> bool { self.has(DG_CONTROL) }
+	pub fn image(&self)   -> bool { self.has(DG_IMAGE) }
+	pub fn audio(&self)   -> bool { self.has(DG_AUDIO) }
+	pub fn dsm2(&self)    -> bool { self.has(DF_DSM2) }
+	pub fn app2(&self)    -> bool { self.has(DF_APP2) }
+	pub fn ds2(&self)     -> bool { self.has(DF_DS2) }
+}
+
+impl DataSourceInfo {
+	pub fn from_identity(identity: TW_IDENTITY) -> Self {
+		Self {
+			id: identity.Id,
+			manufacturer: tw_str32_to_string(&identity.Manufacturer),
+			product_family: tw_str32_to_string(&identity.ProductFamily),
+			product_name: tw_str32_to_string(&identity.ProductName),
+			version: VersionInfo::from_tw_version(&identity.Version),
+			protocol_major: identity.ProtocolMajor,
+			protocol_minor: identity.ProtocolMinor,
+			supported_groups: SupportedGroups(identity.SupportedGroups),
+			identity,
+		}
+	}
+}
+
+impl From<TW_IDENTITY> for DataSourceInfo {
+	fn from(identity: TW_IDENTITY) -> Self {
+		Self::from_identity(identity)
+	}
+}
+
+impl fmt::Display for VersionInfo {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		if self.info.is_empty() {
+			write!(f, "{}.{}", self.major, self.minor)
+		} else {
+			write!(f, "{}.{} ({})", self.major, self.minor, self.info)
+		}
+	}
+}
+
+impl fmt::Display for SupportedGroups {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		let names = [
+			(DG_CONTROL, "DG_CONTROL"),
+			(DG_IMAGE,   "DG_IMAGE"),
+			(DG_AUDIO,   "DG_AUDIO"),
+			(DF_DSM2,    "DF_DSM2"),
+			(DF_APP2,    "DF_APP2"),
+			(DF_DS2,     "DF_DS2"),
+		];
+		let set: Vec<&str> = names.iter().filter(|(flag, _)| self.has(*flag)).map(|(_, name)| *name).collect();
+		write!(f, "{}", set.join("|"))
+	}
+}
+
+impl fmt::Display for DataSourceInfo {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		write!(f, "{} / {} {} (TWAIN {}.{})", self.manufacturer, self.product_name, self.version, self.protocol_major, self.protocol_minor)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn test_identity() -> TW_IDENTITY {
+		TW_IDENTITY {
+			Id: 7,
+			Version: TW_VERSION {
+				MajorNum: 2,
+				MinorNum: 1,
+				Language: TWLG_ENGLISH_USA as TW_UINT16,
+				Country: TWCY_USA as TW_UINT16,
+				Info: tw_str32("2.1.3 sample release"),
+			},
+			ProtocolMajor: 2,
+			ProtocolMinor: 4,
+			SupportedGroups: DG_CONTROL | DG_IMAGE | DF_DS2 | DF_DSM2,
+			Manufacturer: tw_str32("TWAIN Working Group"),
+			ProductFamily: tw_str32("Software Scan"),
+			ProductName: tw_str32("TWAIN2 Software Scanner"),
+		}
+	}
+
+	#[test]
+	fn decodes_identity_strings() {
+		let info = DataSourceInfo::from_identity(test_identity());
+		assert_eq!(7, info.id);
+		assert_eq!("TWAIN Working Group", info.manufacturer);
+		assert_eq!("Software Scan", info.product_family);
+		assert_eq!("TWAIN2 Software Scanner", info.product_name);
+		assert_eq!("2.1.3 sample release", info.version.info);
+		assert_eq!((2, 1), (info.version.major, info.version.minor));
+		assert_eq!((2, 4), (info.protocol_major, info.protocol_minor));
+	}
+
+	#[test]
+	fn decodes_supported_groups() {
+		let groups = DataSourceInfo::from_identity(test_identity()).supported_groups;
+		assert!(groups.control());
+		assert!(groups.image());
+		assert!(!groups.audio());
+		assert!(groups.ds2());
+		assert!(groups.dsm2());
+		assert!(!groups.app2());
+		assert_eq!("DG_CONTROL|DG_IMAGE|DF_DSM2|DF_DS2", groups.to_string());
+	}
+}
@@ -0,0 +1,414 @@
+use super::blank::BlankDecision;
+use super::extimageinfo::ExtImageInfo;
+
+use std::io::{self, Write};
+
+pub const BITMAPFILEHEADER_SIZE: usize = 14;
+pub const BITMAPINFOHEADER_SIZE: usize = 40;
+const INCHES_PER_METER: f64 = 39.3701;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PixelFormat {
+	BlackWhite,
+	Gray8,
+	Rgb24,
+}
+
+// Pixel data is stored top-down with tightly packed rows. Black & white images use one bit per pixel, MSB first,
+// with 0 as black; grayscale uses 0 as black.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+	pub width: u32,
+	pub height: u32,
+	pub format: PixelFormat,
+	pub x_resolution: f64,
+	pub y_resolution: f64,
+	pub data: Vec<u8>,
+	pub ext_info: ExtImageInfo,
+	pub blank: Option<BlankDecision>,
+}
+
+impl PixelFormat {
+	pub fn bits_per_pixel(&self) -> u32 {
+		match self {
+			Self::BlackWhite => 1,
+			Self::Gray8      => 8,
+			Self::Rgb24      => 24,
+		}
+	}
+
+	pub fn samples_per_pixel(&self) -> u32 {
+		match self {
+			Self::BlackWhite | Self::Gray8 => 1,
+			Self::Rgb24 => 3,
+		}
+	}
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+	Some(u16::from_le_bytes(bytes.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+	Some(u32::from_le_bytes(bytes.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn read_i32(bytes: &[u8], offset: usize) -> Option<i32> {
+	read_u32(bytes, offset).map(|v| v as i32)
+}
+
+fn dib_stride(width: u32, bit_count: u32) -> usize {
+	(width as usize * bit_count as usize).div_ceil(32) * 4
+}
+
+fn dib_palette_entries(header: &[u8]) -> Option<usize> {
+	let bit_count = read_u16(header, 14)? as u32;
+	let clr_used = read_u32(header, 32)? as usize;
+	Some(match (clr_used, bit_count) {
+		(0, 1..=8) => 1 << bit_count,
+		(n, 1..=8) => n,
+		_ => 0,
+	})
+}
+
+// Returns the total length of the packed DIB (header, palette and pixels) starting with a BITMAPINFOHEADER
+pub fn dib_len(header: &[u8]) -> Option<usize> {
+	let header_size = read_u32(header, 0)? as usize;
+	let width = read_i32(header, 4)?;
+	let height = read_i32(header, 8)?;
+	let bit_count = read_u16(header, 14)? as u32;
+	let pixels = dib_stride(width.unsigned_abs(), bit_count) * height.unsigned_abs() as usize;
+	Some(header_size + dib_palette_entries(header)? * 4 + pixels)
+}
+
+impl Image {
+	pub fn new(width: u32, height: u32, format: PixelFormat) -> Self {
+		let data = vec![0; Self::stride_for(width, format) * height as usize];
+		Self { width, height, format, x_resolution: 0.0, y_resolution: 0.0, data, ext_info: ExtImageInfo::new(), blank: None }
+	}
+
+	fn stride_for(width: u32, format: PixelFormat) -> usize {
+		(width as usize * format.bits_per_pixel() as usize).div_ceil(8)
+	}
+
+	pub fn stride(&self) -> usize {
+		Self::stride_for(self.width, self.format)
+	}
+
+	pub fn row(&self, y: u32) -> &[u8] {
+		let stride = self.stride();
+		&self.data[y as usize * stride..(y as usize + 1) * stride]
+	}
+
+	// Luminance of the given pixel, 0 being black and 255 white
+	pub fn luminance(&self, x: u32, y: u32) -> u8 {
+		let row = self.row(y);
+		match self.format {
+			PixelFormat::BlackWhite => if row[x as usize / 8] & (0x80 >> (x % 8)) != 0 { 255 } else { 0 },
+			PixelFormat::Gray8 => row[x as usize],
+			PixelFormat::Rgb24 => {
+				let p = &row[x as usize * 3..x as usize * 3 + 3];
+				((p[0] as u32 * 299 + p[1] as u32 * 587 + p[2] as u32 * 114) / 1000) as u8
+			},
+		}
+	}
+
+	// Fraction of pixels darker than mid-gray
+	pub fn ink_coverage(&self) -> f64 {
+		self.ink_coverage_inside(0.0)
+	}
+
+	// Same as ink_coverage() but ignoring this fraction of the width and height on each edge
+	pub fn ink_coverage_inside(&self, margin: f64) -> f64 {
+		let margin_x = (self.width as f64 * margin) as u32;
+		let margin_y = (self.height as f64 * margin) as u32;
+		let (xs, ys) = (margin_x..self.width.saturating_sub(margin_x), margin_y..self.height.saturating_sub(margin_y));
+
+		let total = xs.len() as u64 * ys.len() as u64;
+		if total == 0 {
+			return 0.0;
+		}
+
+		let dark: u64 = ys.map(|y| xs.clone().filter(|x| self.luminance(*x, y) < 128).count() as u64).sum();
+		dark as f64 / total as f64
+	}
+
+	pub fn from_dib(dib: &[u8]) -> Option<Self> {
+		let header_size = read_u32(dib, 0)? as usize;
+		let width = read_i32(dib, 4)?;
+		let height = read_i32(dib, 8)?;
+		let bit_count = read_u16(dib, 14)? as u32;
+		let compression = read_u32(dib, 16)?;
+		let x_ppm = read_i32(dib, 24)?;
+		let y_ppm = read_i32(dib, 28)?;
+
+		if compression != 0 || width <= 0 || height == 0 {
+			return None;
+		}
+
+		let palette_entries = dib_palette_entries(dib)?;
+		let palette = dib.get(header_size..header_size + palette_entries * 4)?;
+		// Pixel values come from the source, an index past the palette makes the DIB invalid
+		let palette_gray = |i: usize| -> Option<u8> {
+			let p = palette.get(i * 4..i * 4 + 4)?;
+			Some(((p[2] as u32 * 299 + p[1] as u32 * 587 + p[0] as u32 * 114) / 1000) as u8)
+		};
+
+		let format = match bit_count {
+			1 => PixelFormat::BlackWhite,
+			8 => PixelFormat::Gray8,
+			24 | 32 => PixelFormat::Rgb24,
+			_ => return None,
+		};
+
+		let (width, top_down, height) = (width as u32, height < 0, height.unsigned_abs());
+		let src_stride = dib_stride(width, bit_count);
+		let pixels = dib.get(header_size + palette_entries * 4..)?;
+		if pixels.len() < src_stride * height as usize {
+			return None;
+		}
+
+		let mut image = Self::new(width, height, format);
+		image.x_resolution = (x_ppm as f64 / INCHES_PER_METER).round();
+		image.y_resolution = (y_ppm as f64 / INCHES_PER_METER).round();
+
+		let invert_bw = bit_count == 1 && palette_gray(0)? > palette_gray(1)?;
+		let dst_stride = image.stride();
+
+		for y in 0..height as usize {
+			let src_y = if top_down { y } else { height as usize - 1 - y };
+			let src = &pixels[src_y * src_stride..(src_y + 1) * src_stride];
+			let dst = &mut image.data[y * dst_stride..(y + 1) * dst_stride];
+
+			match bit_count {
+				1 => {
+					dst.copy_from_slice(&src[..dst_stride]);
+					if invert_bw {
+						dst.iter_mut().for_each(|b| *b = !*b);
+					}
+				},
+				8 => {
+					for (d, s) in dst.iter_mut().zip(src) {
+						*d = palette_gray(*s as usize)?;
+					}
+				},
+				_ => {
+					let bytes_pp = bit_count as usize / 8;
+					for x in 0..width as usize {
+						let s = &src[x * bytes_pp..x * bytes_pp + 3];
+						dst[x * 3..x * 3 + 3].copy_from_slice(&[s[2], s[1], s[0]]);
+					}
+				},
+			}
+		}
+
+		Some(image)
+	}
+
+	pub fn from_bmp(bmp: &[u8]) -> Option<Self> {
+		if bmp.get(0..2)? != b"BM" {
+			return None;
+		}
+		Self::from_dib(bmp.get(BITMAPFILEHEADER_SIZE..)?)
+	}
+
+	pub fn to_bmp(&self) -> Vec<u8> {
+		let bit_count = self.format.bits_per_pixel();
+		let palette: Vec<u8> = match self.format {
+			PixelFormat::BlackWhite => vec![0, 0, 0, 0, 255, 255, 255, 0],
+			PixelFormat::Gray8 => (0..=255u8).flat_map(|i| [i, i, i, 0]).collect(),
+			PixelFormat::Rgb24 => Vec::new(),
+		};
+		let dst_stride = dib_stride(self.width, bit_count);
+		let pixel_offset = BITMAPFILEHEADER_SIZE + BITMAPINFOHEADER_SIZE + palette.len();
+		let file_size = pixel_offset + dst_stride * self.height as usize;
+		let ppm = |dpi: f64| (dpi * INCHES_PER_METER).round() as i32;
+
+		let mut bmp = Vec::with_capacity(file_size);
+		bmp.extend_from_slice(b"BM");
+		bmp.extend_from_slice(&(file_size as u32).to_le_bytes());
+		bmp.extend_from_slice(&0u32.to_le_bytes());
+		bmp.extend_from_slice(&(pixel_offset as u32).to_le_bytes());
+
+		bmp.extend_from_slice(&(BITMAPINFOHEADER_SIZE as u32).to_le_bytes());
+		bmp.extend_from_slice(&(self.width as i32).to_le_bytes());
+		bmp.extend_from_slice(&(self.height as i32).to_le_bytes());
+		bmp.extend_from_slice(&1u16.to_le_bytes());
+		bmp.extend_from_slice(&(bit_count as u16).to_le_bytes());
+		bmp.extend_from_slice(&0u32.to_le_bytes());
+		bmp.extend_from_slice(&((dst_stride * self.height as usize) as u32).to_le_bytes());
+		bmp.extend_from_slice(&ppm(self.x_resolution).to_le_bytes());
+		bmp.extend_from_slice(&ppm(self.y_resolution).to_le_bytes());
+		bmp.extend_from_slice(&((palette.len() / 4) as u32).to_le_bytes());
+		bmp.extend_from_slice(&0u32.to_le_bytes());
+		bmp.extend_from_slice(&palette);
+
+		for y in (0..self.height).rev() {
+			let row = self.row(y);
+			let start = bmp.len();
+			if self.format == PixelFormat::Rgb24 {
+				row.chunks(3).for_each(|p| bmp.extend_from_slice(&[p[2], p[1], p[0]]));
+			} else {
+				bmp.extend_from_slice(row);
+			}
+			bmp.resize(start + dst_stride, 0);
+		}
+
+		bmp
+	}
+}
+
+fn tiff_entry(ifd: &mut Vec<u8>, tag: u16, typ: u16, count: u32, value: u32) {
+	ifd.extend_from_slice(&tag.to_le_bytes());
+	ifd.extend_from_slice(&typ.to_le_bytes());
+	ifd.extend_from_slice(&count.to_le_bytes());
+	ifd.extend_from_slice(&value.to_le_bytes());
+}
+
+pub fn write_multipage_tiff<W: Write>(w: &mut W, images: &[Image]) -> io::Result<()> {
+	const SHORT: u16 = 3;
+	const LONG: u16 = 4;
+	const RATIONAL: u16 = 5;
+
+	let mut tiff = Vec::new();
+	tiff.extend_from_slice(b"II");
+	tiff.extend_from_slice(&42u16.to_le_bytes());
+	tiff.extend_from_slice(&0u32.to_le_bytes());
+
+	let mut next_ifd_pointer = 4;
+
+	for image in images {
+		let strip_offset = tiff.len() as u32;
+		tiff.extend_from_slice(&image.data);
+		if tiff.len() % 2 != 0 {
+			tiff.push(0);
+		}
+
+		let bps_offset = tiff.len() as u32;
+		let samples = image.format.samples_per_pixel();
+		let bps = image.format.bits_per_pixel() / samples;
+		if samples > 1 {
+			(0..samples).for_each(|_| tiff.extend_from_slice(&(bps as u16).to_le_bytes()));
+		}
+
+		let res_offset = tiff.len() as u32;
+		for dpi in [image.x_resolution, image.y_resolution] {
+			tiff.extend_from_slice(&((dpi.max(1.0) * 100.0).round() as u32).to_le_bytes());
+			tiff.extend_from_slice(&100u32.to_le_bytes());
+		}
+
+		let photometric = match image.format {
+			PixelFormat::BlackWhite | PixelFormat::Gray8 => 1,
+			PixelFormat::Rgb24 => 2,
+		};
+
+		let mut ifd = Vec::new();
+		let entries = 12u16;
+		ifd.extend_from_slice(&entries.to_le_bytes());
+		tiff_entry(&mut ifd, 256, LONG, 1, image.width);
+		tiff_entry(&mut ifd, 257, LONG, 1, image.height);
+		tiff_entry(&mut ifd, 258, SHORT, samples, if samples > 1 { bps_offset } else { bps });
+		tiff_entry(&mut ifd, 259, SHORT, 1, 1);
+		tiff_entry(&mut ifd, 262, SHORT, 1, photometric);
+		tiff_entry(&mut ifd, 273, LONG, 1, strip_offset);
+		tiff_entry(&mut ifd, 277, SHORT, 1, samples);
+		tiff_entry(&mut ifd, 278, LONG, 1, image.height);
+		tiff_entry(&mut ifd, 279, LONG, 1, image.data.len() as u32);
+		tiff_entry(&mut ifd, 282, RATIONAL, 1, res_offset);
+		tiff_entry(&mut ifd, 283, RATIONAL, 1, res_offset + 8);
+		tiff_entry(&mut ifd, 296, SHORT, 1, 2);
+
+		let ifd_offset = tiff.len() as u32;
+		tiff[next_ifd_pointer..next_ifd_pointer + 4].copy_from_slice(&ifd_offset.to_le_bytes());
+		tiff.extend_from_slice(&ifd);
+		next_ifd_pointer = tiff.len();
+		tiff.extend_from_slice(&0u32.to_le_bytes());
+	}
+
+	w.write_all(&tiff)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::capability::CapValue;
+	use super::super::extimageinfo::{ExtInfo, ExtInfoItem};
+	use super::super::twain_h::*;
+
+	fn test_image(format: PixelFormat) -> Image {
+		let mut image = Image::new(5, 3, format);
+		image.x_resolution = 300.0;
+		image.y_resolution = 200.0;
+		image.data.iter_mut().enumerate().for_each(|(i, b)| *b = (i * 37) as u8);
+		if format == PixelFormat::BlackWhite {
+			image.data.iter_mut().for_each(|b| *b &= 0xf8);
+		}
+		image
+	}
+
+	#[test]
+	fn bmp_round_trip() {
+		for format in [PixelFormat::BlackWhite, PixelFormat::Gray8, PixelFormat::Rgb24] {
+			let image = test_image(format);
+			let bmp = image.to_bmp();
+			assert_eq!(Some(bmp.len() - BITMAPFILEHEADER_SIZE), dib_len(&bmp[BITMAPFILEHEADER_SIZE..]));
+			assert_eq!(Some(image), Image::from_bmp(&bmp));
+		}
+	}
+
+	#[test]
+	fn inverted_bw_palette() {
+		let image = test_image(PixelFormat::BlackWhite);
+		let mut bmp = image.to_bmp();
+		let palette = BITMAPFILEHEADER_SIZE + BITMAPINFOHEADER_SIZE;
+		bmp[palette..palette + 8].copy_from_slice(&[255, 255, 255, 0, 0, 0, 0, 0]);
+		let pixel_offset = read_u32(&bmp, 10).unwrap() as usize;
+		bmp[pixel_offset..].iter_mut().for_each(|b| *b = !*b);
+		let decoded = Image::from_bmp(&bmp).unwrap();
+		assert_eq!(image.luminance(0, 0), decoded.luminance(0, 0));
+		assert_eq!(image.luminance(1, 2), decoded.luminance(1, 2));
+	}
+
+	#[test]
+	fn pixel_index_past_palette() {
+		// 1x1 8 bit DIB with a two entry palette
+		let mut dib = vec![0u8; BITMAPINFOHEADER_SIZE + 2 * 4 + 4];
+		dib[0] = BITMAPINFOHEADER_SIZE as u8;
+		dib[4] = 1;
+		dib[8] = 1;
+		dib[12] = 1;
+		dib[14] = 8;
+		dib[32] = 2;
+		let pixel = BITMAPINFOHEADER_SIZE + 2 * 4;
+
+		dib[pixel] = 1;
+		assert!(Image::from_dib(&dib).is_some());
+		dib[pixel] = 5;
+		assert_eq!(None, Image::from_dib(&dib));
+	}
+
+	#[test]
+	fn tiff_pages_are_linked() {
+		let images = [test_image(PixelFormat::Gray8), test_image(PixelFormat::Rgb24)];
+		let mut tiff = Vec::new();
+		write_multipage_tiff(&mut tiff, &images).unwrap();
+
+		assert_eq!(b"II*\0", &tiff[0..4]);
+		let mut ifd = read_u32(&tiff, 4).unwrap() as usize;
+		let mut pages = 0;
+		while ifd != 0 {
+			let entries = read_u16(&tiff, ifd).unwrap() as usize;
+			assert_eq!(Some(images[pages].width), read_u32(&tiff, ifd + 2 + 8));
+			ifd = read_u32(&tiff, ifd + 2 + entries * 12).unwrap() as usize;
+			pages += 1;
+		}
+		assert_eq!(2, pages);
+	}
+
+	#[test]
+	fn images_move_between_threads() {
+		let mut image = test_image(PixelFormat::Gray8);
+		image.ext_info.insert(TWEI_DESKEWSTATUS as TW_UINT16, ExtInfo::Items(vec![ExtInfoItem::Value(CapValue::UInt16(1))]));
+		assert_eq!(image.clone(), std::thread::spawn(move || image).join().unwrap());
+	}
+}
@@ -0,0 +1,169 @@
+use super::{DSMEntryWrapper, Triplet};
+use super::response::*;
+use super::twain_h::*;
+
+use std::sync::Arc;
+
+// Identities are copies, before() sees them as they were passed in and after() as the DSM left them
+pub struct DSMCall {
+	pub origin: Option<TW_IDENTITY>,
+	pub dest: Option<TW_IDENTITY>,
+	pub triplet: Triplet,
+	pub data: TW_MEMREF,
+}
+
+// Interceptors run in the order they were added before the call, and in reverse order after it
+pub trait Interceptor: Send + Sync {
+	// Returning a response skips the DSM and the remaining interceptors, the response is passed to after() instead
+	fn before(&self, _call: &DSMCall) -> Option<Response> {
+		None
+	}
+
+	fn after(&self, _call: &DSMCall, _res: &mut Response) {}
+
+	// Callbacks from the source to the application, only observed
+	fn callback(&self, _call: &DSMCall) {}
+}
+
+pub struct LogInterceptor;
+
+impl Interceptor for LogInterceptor {
+	fn after(&self, call: &DSMCall, res: &mut Response) {
+		log::debug!("DSM call {}: {}", call.triplet, res);
+	}
+}
+
+impl DSMEntryWrapper {
+	pub fn add_interceptor(&self, interceptor: Arc<dyn Interceptor>) {
+		self.interceptors.write().push(interceptor);
+	}
+
+	pub fn clear_interceptors(&self) {
+		self.interceptors.write().clear();
+	}
+
+	pub fn notify_callback(&self, call: &DSMCall) {
+		let interceptors = self.interceptors.read().clone();
+		interceptors.iter().for_each(|interceptor| interceptor.callback(call));
+	}
+
+	pub fn intercept<C: Fn() -> DSMCall, F: FnOnce() -> Response>(&self, call: C, dsm_call: F) -> Response {
+		// Cloned so interceptors can add or remove interceptors without deadlocking
+		let interceptors = self.interceptors.read().clone();
+
+		let before = call();
+
+		let mut ran = 0;
+		let mut short_circuit = None;
+		for interceptor in &interceptors {
+			ran += 1;
+			if let Some(res) = interceptor.before(&before) {
+				short_circuit = Some(res);
+				break;
+			}
+		}
+
+		let mut res = match short_circuit {
+			Some(res) => res,
+			None => dsm_call(),
+		};
+
+		let after = call();
+		for interceptor in interceptors[..ran].iter().rev() {
+			interceptor.after(&after, &mut res);
+		}
+
+		res
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::twain_h_ext::*;
+
+	use parking_lot::Mutex;
+	use std::ptr;
+
+	struct Recorder {
+		name: &'static str,
+		log: Arc<Mutex<Vec<String>>>,
+		short_circuit: Option<Triplet>,
+	}
+
+	impl Interceptor for Recorder {
+		fn before(&self, call: &DSMCall) -> Option<Response> {
+			self.log.lock().push(format!("{} before", self.name));
+			(self.short_circuit == Some(call.triplet)).then_some(Response {
+				return_code: ReturnCode::Failure,
+				condition_code: ConditionCode::Bummer,
+			})
+		}
+
+		fn after(&self, _call: &DSMCall, res: &mut Response) {
+			self.log.lock().push(format!("{} after {}", self.name, res));
+		}
+	}
+
+	struct IdRecorder(Mutex<Vec<TW_UINT32>>);
+
+	impl Interceptor for IdRecorder {
+		fn before(&self, call: &DSMCall) -> Option<Response> {
+			self.0.lock().extend(call.origin.map(|id| id.Id));
+			None
+		}
+
+		fn after(&self, call: &DSMCall, _res: &mut Response) {
+			self.0.lock().extend(call.origin.map(|id| id.Id));
+		}
+	}
+
+	extern "C" fn fake_dsm_entry(origin: pTW_IDENTITY, _dest: pTW_IDENTITY, _dg: TW_UINT32, dat: TW_UINT16, msg: TW_UINT16, data: TW_MEMREF) -> TW_UINT16 {
+		if dat as TwainUConst == DAT_STATUS {
+			unsafe { (*(data as pTW_STATUS)).ConditionCode = TWCC_SUCCESS as TW_UINT16 };
+		} else if msg as TwainUConst == MSG_OPENDSM && !origin.is_null() {
+			unsafe { (*origin).Id = 7 };
+		}
+		TWRC_SUCCESS as TW_UINT16
+	}
+
+	#[test]
+	fn interceptors_wrap_calls_in_order_and_can_short_circuit() {
+		let wrapper = DSMEntryWrapper::from_dsmentryproc(Some(fake_dsm_entry)).unwrap();
+		let log = Arc::new(Mutex::new(Vec::new()));
+		let close = Triplet::new(DG_CONTROL, DAT_PARENT, MSG_CLOSEDSM);
+
+		wrapper.add_interceptor(Arc::new(Recorder { name: "outer", log: log.clone(), short_circuit: None }));
+		wrapper.add_interceptor(Arc::new(Recorder { name: "inner", log: log.clone(), short_circuit: Some(close) }));
+
+		let res = wrapper.do_dsm_entry(None, None, DG_CONTROL, DAT_PARENT, MSG_OPENDSM, ptr::null_mut());
+		assert!(res.is_success());
+
+		let res = wrapper.do_dsm_entry(None, None, close.dg, close.dat, close.msg, ptr::null_mut());
+		assert_eq!(ConditionCode::Bummer, res.condition_code);
+
+		let success = Response { return_code: ReturnCode::Success, condition_code: ConditionCode::Success };
+		let bummer = Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::Bummer };
+		assert_eq!(vec![
+			"outer before".to_string(),
+			"inner before".to_string(),
+			format!("inner after {}", success),
+			format!("outer after {}", success),
+			"outer before".to_string(),
+			"inner before".to_string(),
+			format!("inner after {}", bummer),
+			format!("outer after {}", bummer),
+		], *log.lock());
+	}
+
+	#[test]
+	fn after_sees_identities_written_by_the_dsm() {
+		let wrapper = DSMEntryWrapper::from_dsmentryproc(Some(fake_dsm_entry)).unwrap();
+		let ids = Arc::new(IdRecorder(Mutex::new(Vec::new())));
+		wrapper.add_interceptor(ids.clone());
+
+		let mut app_identity = TW_IDENTITY::default();
+		wrapper.do_dsm_entry(Some(&mut app_identity), None, DG_CONTROL, DAT_PARENT, MSG_OPENDSM, ptr::null_mut());
+		assert_eq!(vec![0, 7], *ids.0.lock());
+	}
+}
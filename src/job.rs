@@ -0,0 +1,163 @@
+use super::{DSError, DSState, OpenedDS};
+use super::capability::*;
+use super::image::Image;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JobControl {
+	None,
+	IncludeSeparatorContinue,
+	IncludeSeparatorStop,
+	ExcludeSeparatorContinue,
+	ExcludeSeparatorStop,
+}
+
+// With the include modes the separator sheet is transferred as the last image of its job
+#[derive(Debug, Clone, PartialEq)]
+pub struct Job {
+	pub images: Vec<Image>,
+	pub end_of_job: TW_UINT32,
+}
+
+pub struct Jobs<'a, F> {
+	ds: &'a OpenedDS,
+	acquire: F,
+}
+
+impl JobControl {
+	pub fn from_twjc(value: TW_UINT16) -> Option<Self> {
+		Some(match value as TwainUConst {
+			TWJC_NONE => Self::None,
+			TWJC_JSIC => Self::IncludeSeparatorContinue,
+			TWJC_JSIS => Self::IncludeSeparatorStop,
+			TWJC_JSXC => Self::ExcludeSeparatorContinue,
+			TWJC_JSXS => Self::ExcludeSeparatorStop,
+			_ => return None,
+		})
+	}
+
+	pub fn to_twjc(&self) -> TW_UINT16 {
+		(match self {
+			Self::None => TWJC_NONE,
+			Self::IncludeSeparatorContinue => TWJC_JSIC,
+			Self::IncludeSeparatorStop     => TWJC_JSIS,
+			Self::ExcludeSeparatorContinue => TWJC_JSXC,
+			Self::ExcludeSeparatorStop     => TWJC_JSXS,
+		}) as TW_UINT16
+	}
+
+	pub fn stops_after_job(&self) -> bool {
+		matches!(self, Self::IncludeSeparatorStop | Self::ExcludeSeparatorStop)
+	}
+}
+
+impl Job {
+	pub fn is_complete(&self) -> bool {
+		self.end_of_job != TWEJ_NONE
+	}
+}
+
+impl OpenedDS {
+	pub fn supported_job_controls(&self) -> Result<Vec<JobControl>, DSError> {
+		let container = self.get_capability(CAP_JOBCONTROL)?;
+		Ok(container.values().iter().filter_map(|v| v.as_u32()).filter_map(|v| JobControl::from_twjc(v as TW_UINT16)).collect())
+	}
+
+	pub fn set_job_control(&self, mode: JobControl) -> Result<(), DSError> {
+		self.set_capability_value(CAP_JOBCONTROL, CapValue::UInt16(mode.to_twjc()))
+	}
+
+	// TWEJ_ value reported when the last transfer ended, TWEJ_NONE unless it closed a job
+	pub fn end_of_job(&self) -> TW_UINT32 {
+		self.last_transfer().map_or(TWEJ_NONE, |outcome| outcome.end_of_job)
+	}
+
+	// Acquires images until the source signals the end of a job or runs out of transfers
+	pub fn acquire_job<F: FnMut(&Self) -> Result<Option<Image>, DSError>>(&self, mut acquire: F) -> Result<Option<Job>, DSError> {
+		if self.get_state() != DSState::TransferReady {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let mut images = Vec::new();
+		let mut end_of_job = TWEJ_NONE;
+
+		while self.get_state() == DSState::TransferReady {
+			if let Some(image) = acquire(self)? {
+				images.push(image);
+			}
+
+			end_of_job = self.end_of_job();
+			if end_of_job != TWEJ_NONE {
+				log::debug!("End of job {} on \"{}\" after {} image(s)", end_of_job, self.name, images.len());
+				break;
+			}
+		}
+
+		if images.is_empty() && end_of_job == TWEJ_NONE {
+			return Ok(None);
+		}
+
+		Ok(Some(Job { images, end_of_job }))
+	}
+
+	pub fn jobs<F: FnMut(&Self) -> Result<Option<Image>, DSError>>(&self, acquire: F) -> Jobs<'_, F> {
+		Jobs { ds: self, acquire }
+	}
+}
+
+impl<F: FnMut(&OpenedDS) -> Result<Option<Image>, DSError>> Iterator for Jobs<'_, F> {
+	type Item = Result<Job, DSError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.ds.get_state() != DSState::TransferReady {
+			return None;
+		}
+
+		self.ds.acquire_job(&mut self.acquire).transpose()
+	}
+}
+
+impl fmt::Display for JobControl {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::None => write!(f, "None"),
+			Self::IncludeSeparatorContinue => write!(f, "IncludeSeparatorContinue"),
+			Self::IncludeSeparatorStop     => write!(f, "IncludeSeparatorStop"),
+			Self::ExcludeSeparatorContinue => write!(f, "ExcludeSeparatorContinue"),
+			Self::ExcludeSeparatorStop     => write!(f, "ExcludeSeparatorStop"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::fake::*;
+	use super::super::transfer::TransferOutcome;
+
+	#[test]
+	fn job_control_round_trips() {
+		for mode in [JobControl::None, JobControl::IncludeSeparatorContinue, JobControl::IncludeSeparatorStop, JobControl::ExcludeSeparatorContinue, JobControl::ExcludeSeparatorStop] {
+			assert_eq!(Some(mode), JobControl::from_twjc(mode.to_twjc()));
+		}
+		assert_eq!(None, JobControl::from_twjc(42));
+		assert!(JobControl::ExcludeSeparatorStop.stops_after_job());
+		assert!(!JobControl::IncludeSeparatorContinue.stops_after_job());
+	}
+
+	#[test]
+	fn end_of_job_is_reset_for_a_new_batch() {
+		let (wrapper, _) = fake_dsm();
+		let ds = open_fake_source(wrapper);
+
+		*ds.last_transfer.write() = Some(TransferOutcome { count: 0, end_of_job: TWEJ_PATCHT, finished: true });
+		assert_eq!(TWEJ_PATCHT, ds.end_of_job());
+
+		ds.enable(no_ui()).unwrap();
+		assert_eq!(TWEJ_NONE, ds.end_of_job());
+		assert_eq!(None, ds.last_transfer());
+	}
+}
@@ -1,10 +1,36 @@
+pub mod barcode;
+pub mod blank;
+pub mod capability;
 pub mod data;
+pub mod detection;
+pub mod deviceevent;
+#[cfg(target_os = "linux")]
+pub mod discovery;
+pub mod doublefeed;
+pub mod duplex;
 pub mod entrypoint;
+pub mod extimageinfo;
+#[cfg(test)]
+mod fake;
+pub mod fault;
+pub mod feeder;
+pub mod identity;
+pub mod image;
+pub mod interceptor;
+pub mod job;
+pub mod patchcode;
+#[cfg(feature = "picker")]
+pub mod picker;
+pub mod recovery;
 pub mod response;
+pub mod retry;
+pub mod session;
+pub mod transfer;
 pub mod twain_h;
 pub mod twain_h_ext;
 
 use entrypoint::*;
+use identity::*;
 use response::*;
 use twain_h::*;
 use twain_h_ext::*;
@@ -13,11 +39,15 @@ use std::fmt;
 use std::mem::MaybeUninit;
 use std::ptr;
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 use parking_lot::RwLock;
 
 pub struct DSMEntryWrapper {
 	entry_proc: Box<dyn Fn(*mut TW_IDENTITY, *mut TW_IDENTITY, TW_UINT32, TW_UINT16, TW_UINT16, TW_MEMREF) -> TW_UINT16 + Send + Sync>,
 	_libloading_library: Option<libloading::Library>,
+	retry_policy: RwLock<Option<retry::RetryPolicy>>,
+	interceptors: RwLock<Vec<Arc<dyn interceptor::Interceptor>>>,
 }
 
 pub struct OpenedDSM {
@@ -31,7 +61,21 @@ pub struct OpenedDS {
 	pub ds_identity: RwLock<TW_IDENTITY>,
 	pub dsm: Arc<OpenedDSM>,
 	pub ui: RwLock<Option<TW_USERINTERFACE>>,
+	pub ext_image_info_ids: RwLock<Vec<extimageinfo::TweiId>>,
+	pub blank_detector: RwLock<Option<blank::BlankDetector>>,
 	state: RwLock<DSState>,
+	last_transfer: RwLock<Option<transfer::TransferOutcome>>,
+	device_event_subscribers: RwLock<Vec<deviceevent::DeviceEventSubscriber>>,
+	pending_device_events: RwLock<usize>,
+	close_requested: RwLock<bool>,
+	retry_policy: RwLock<Option<retry::RetryPolicy>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Triplet {
+	pub dg: TwainUConst,
+	pub dat: TwainUConst,
+	pub msg: TwainUConst,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -46,31 +90,64 @@ pub enum DSState {
 pub enum DSError {
 	InvalidState(DSState),
 	BadResponse(Response),
+	MemoryError,
+	BadContainer(TW_UINT16),
+	BadImage,
 }
 
 fn id_to_label(id: &TW_IDENTITY) -> String {
 	tw_str32_to_string(&id.ProductName)
 }
 
+impl Triplet {
+	pub fn new(dg: TwainUConst, dat: TwainUConst, msg: TwainUConst) -> Self {
+		Self { dg, dat, msg }
+	}
+}
+
 impl DSMEntryWrapper {
 	pub fn from_dsmentryproc(dsm_entry: DSMENTRYPROC) -> Option<Self> {
 		let dsm_entry = dsm_entry?;
 		let entry_proc = move |origin: *mut TW_IDENTITY, dest: *mut TW_IDENTITY, dg: TW_UINT32, dat: TW_UINT16, msg: TW_UINT16, data: TW_MEMREF| -> TW_UINT16 {
 			unsafe { dsm_entry(origin, dest, dg, dat, msg, data) }
 		};
-		Some(Self { entry_proc: Box::new(entry_proc), _libloading_library: None })
+		Some(Self::from_fn(entry_proc))
+	}
+
+	// For backends that aren't a real DSM, like session replay
+	pub fn from_fn<F: Fn(*mut TW_IDENTITY, *mut TW_IDENTITY, TW_UINT32, TW_UINT16, TW_UINT16, TW_MEMREF) -> TW_UINT16 + Send + Sync + 'static>(entry_proc: F) -> Self {
+		Self { entry_proc: Box::new(entry_proc), _libloading_library: None, retry_policy: RwLock::new(None), interceptors: RwLock::new(Vec::new()) }
 	}
 
 	pub fn from_libloading_library(library: libloading::Library) -> Option<Self> {
-		let dsm_entry_symbol = unsafe { library.get(b"DSM_Entry\0") }.ok()?;
+		Self::from_library(library).ok()
+	}
+
+	pub fn from_path<P: AsRef<std::ffi::OsStr>>(path: P) -> Result<Self, libloading::Error> {
+		let library = unsafe { libloading::Library::new(path.as_ref()) }?;
+		Self::from_library(library)
+	}
+
+	fn from_library(library: libloading::Library) -> Result<Self, libloading::Error> {
+		let dsm_entry_symbol = unsafe { library.get(b"DSM_Entry\0") }?;
 		let dsm_entry: DSMENTRYPROC = Some(*dsm_entry_symbol);
 		let entry_proc = move |origin: *mut TW_IDENTITY, dest: *mut TW_IDENTITY, dg: TW_UINT32, dat: TW_UINT16, msg: TW_UINT16, data: TW_MEMREF| -> TW_UINT16 {
 			unsafe { (dsm_entry.unwrap())(origin, dest, dg, dat, msg, data) }
 		};
-		Some(Self { entry_proc: Box::new(entry_proc), _libloading_library: Some(library) })
+		Ok(Self { _libloading_library: Some(library), ..Self::from_fn(entry_proc) })
+	}
+
+	pub fn set_retry_policy(&self, policy: Option<retry::RetryPolicy>) {
+		*self.retry_policy.write() = policy;
 	}
 
 	pub fn do_dsm_entry(&self, origin: Option<&mut TW_IDENTITY>, dest: Option<&mut TW_IDENTITY>, dg: TwainUConst, dat: TwainUConst, msg: TwainUConst, data: TW_MEMREF) -> Response {
+		// Copied so the lock isn't held across backoff sleeps and interceptors
+		let policy = self.retry_policy.read().clone();
+		self.do_dsm_entry_with_retry(policy.as_ref(), origin, dest, Triplet::new(dg, dat, msg), data)
+	}
+
+	pub fn do_dsm_entry_with_retry(&self, policy: Option<&retry::RetryPolicy>, origin: Option<&mut TW_IDENTITY>, dest: Option<&mut TW_IDENTITY>, triplet: Triplet, data: TW_MEMREF) -> Response {
 		let p_origin = match origin {
 			None => ptr::null_mut(),
 			Some(r) => r as *mut TW_IDENTITY,
@@ -81,9 +158,22 @@ impl DSMEntryWrapper {
 			Some(r) => r as *mut TW_IDENTITY,
 		};
 
+		match policy {
+			Some(policy) => policy.run(triplet, || self.call(p_origin, p_dest, triplet, data)),
+			None => self.call(p_origin, p_dest, triplet, data),
+		}
+	}
+
+	fn call(&self, p_origin: *mut TW_IDENTITY, p_dest: *mut TW_IDENTITY, triplet: Triplet, data: TW_MEMREF) -> Response {
+		// Identities are copied before and again after the call, the DSM writes to them during it
+		let call = || interceptor::DSMCall { origin: unsafe { p_origin.as_ref() }.copied(), dest: unsafe { p_dest.as_ref() }.copied(), triplet, data };
+		self.intercept(call, || self.call_dsm(p_origin, p_dest, triplet, data))
+	}
+
+	fn call_dsm(&self, p_origin: *mut TW_IDENTITY, p_dest: *mut TW_IDENTITY, triplet: Triplet, data: TW_MEMREF) -> Response {
 		let dsm_entry = &self.entry_proc;
 
-		let rc = dsm_entry(p_origin, p_dest, dg as TW_UINT32, dat as TW_UINT16, msg as TW_UINT16, data);
+		let rc = dsm_entry(p_origin, p_dest, triplet.dg as TW_UINT32, triplet.dat as TW_UINT16, triplet.msg as TW_UINT16, data);
 		let return_code = ReturnCode::from_rc(rc);
 
 		let mut tw_status: MaybeUninit<TW_STATUS> = MaybeUninit::uninit();
@@ -130,7 +220,7 @@ impl OpenedDSM {
 		Ok(Arc::new(OpenedDSM { app_identity, entry_points, dsm_entry_wrapper }))
 	}
 
-	pub fn get_data_sources(&self) -> Result<Vec<TW_IDENTITY>, Response> {
+	pub fn get_data_sources(&self) -> Result<Vec<DataSourceInfo>, Response> {
 		let mut data_sources = Vec::new();
 
 		let mut first = true;
@@ -138,7 +228,7 @@ impl OpenedDSM {
 			let mut identity: TW_IDENTITY = Default::default();
 			let res = self.do_dsm_entry(None, DG_CONTROL, DAT_IDENTITY, if first { MSG_GETFIRST } else { MSG_GETNEXT }, &mut identity as *mut TW_IDENTITY as _);
 			match res {
-				Response { return_code: ReturnCode::Success, .. } => data_sources.push(identity),
+				Response { return_code: ReturnCode::Success, .. } => data_sources.push(DataSourceInfo::from_identity(identity)),
 				Response { return_code: ReturnCode::EndOfList, .. } => break,
 				res => return Err(res),
 			}
@@ -148,13 +238,74 @@ impl OpenedDSM {
 		Ok(data_sources)
 	}
 
+	pub fn find_source<P: FnMut(&DataSourceInfo) -> bool>(&self, mut predicate: P) -> Result<Option<DataSourceInfo>, Response> {
+		Ok(self.get_data_sources()?.into_iter().find(|ds| predicate(ds)))
+	}
+
+	pub fn find_sources<P: FnMut(&DataSourceInfo) -> bool>(&self, mut predicate: P) -> Result<Vec<DataSourceInfo>, Response> {
+		Ok(self.get_data_sources()?.into_iter().filter(|ds| predicate(ds)).collect())
+	}
+
+	pub fn find_source_by_name<S: AsRef<str>>(&self, product_name: S) -> Result<Option<DataSourceInfo>, Response> {
+		self.find_source(|ds| ds.product_name == product_name.as_ref())
+	}
+
+	pub fn find_by_manufacturer<S: AsRef<str>>(&self, manufacturer: S) -> Result<Vec<DataSourceInfo>, Response> {
+		self.find_sources(|ds| ds.manufacturer == manufacturer.as_ref())
+	}
+
+	pub fn default_source(&self) -> Result<DataSourceInfo, Response> {
+		let mut identity: TW_IDENTITY = Default::default();
+		let res = self.do_dsm_entry(None, DG_CONTROL, DAT_IDENTITY, MSG_GETDEFAULT, &mut identity as *mut TW_IDENTITY as _);
+		if !res.is_success() {
+			return Err(res);
+		}
+
+		Ok(DataSourceInfo::from_identity(identity))
+	}
+
+	pub fn set_default_source(&self, ds_identity: TW_IDENTITY) -> Result<(), Response> {
+		let mut ds_identity = ds_identity;
+
+		log::debug!("Setting default TWAIN DS to \"{}\"", id_to_label(&ds_identity));
+
+		let res = self.do_dsm_entry(None, DG_CONTROL, DAT_IDENTITY, MSG_SET, &mut ds_identity as *mut TW_IDENTITY as _);
+		if !res.is_success() {
+			return Err(res);
+		}
+
+		Ok(())
+	}
+
+	pub fn user_select_source(&self) -> Result<Option<DataSourceInfo>, Response> {
+		let mut identity: TW_IDENTITY = Default::default();
+
+		log::debug!("Showing TWAIN source selection dialog");
+
+		let res = self.do_dsm_entry(None, DG_CONTROL, DAT_IDENTITY, MSG_USERSELECT, &mut identity as *mut TW_IDENTITY as _);
+		match res {
+			Response { return_code: ReturnCode::Success, .. } => Ok(Some(DataSourceInfo::from_identity(identity))),
+			Response { return_code: ReturnCode::Cancel, .. } => Ok(None),
+			res => Err(res),
+		}
+	}
+
 	pub fn open_data_source(self: &Arc<Self>, ds_identity: TW_IDENTITY) -> Result<Box<OpenedDS>, Response> {
 		OpenedDS::new(self.clone(), ds_identity)
 	}
 
+	pub fn open_default_source(self: &Arc<Self>) -> Result<Box<OpenedDS>, Response> {
+		let ds = self.default_source()?;
+		self.open_data_source(ds.identity)
+	}
+
 	pub fn do_dsm_entry(&self, dest: Option<&mut TW_IDENTITY>, dg: TwainUConst, dat: TwainUConst, msg: TwainUConst, data: TW_MEMREF) -> Response {
 		self.dsm_entry_wrapper.do_dsm_entry(Some(&mut self.app_identity.write()), dest, dg, dat, msg, data)
 	}
+
+	pub fn set_retry_policy(&self, policy: Option<retry::RetryPolicy>) {
+		self.dsm_entry_wrapper.set_retry_policy(policy);
+	}
 }
 
 impl Drop for OpenedDSM {
@@ -172,6 +323,19 @@ impl OpenedDS {
 		*self.state.read()
 	}
 
+	// The source asked to be disabled (MSG_CLOSEDSREQ or MSG_CLOSEDSOK), the application should end its transfers and call disable()
+	pub fn close_requested(&self) -> bool {
+		*self.close_requested.read()
+	}
+
+	pub fn wait_while_state(&self, state: DSState, timeout: Duration) -> DSState {
+		let start = Instant::now();
+		while self.get_state() == state && start.elapsed() < timeout {
+			thread::sleep(Duration::from_millis(10));
+		}
+		self.get_state()
+	}
+
 	fn new(dsm: Arc<OpenedDSM>, ds_identity: TW_IDENTITY) -> Result<Box<Self>, Response> {
 		let ds_identity = RwLock::new(ds_identity);
 		let name = id_to_label(&ds_identity.read());
@@ -183,7 +347,7 @@ impl OpenedDS {
 			return Err(res);
 		}
 
-		let opened_ds = Box::new(Self { name, dsm, ds_identity, ui: RwLock::new(None), state: RwLock::new(DSState::SourceOpen) });
+		let opened_ds = Box::new(Self { name, dsm, ds_identity, ui: RwLock::new(None), ext_image_info_ids: RwLock::new(Vec::new()), blank_detector: RwLock::new(None), state: RwLock::new(DSState::SourceOpen), last_transfer: RwLock::new(None), device_event_subscribers: RwLock::new(Vec::new()), pending_device_events: RwLock::new(0), close_requested: RwLock::new(false), retry_policy: RwLock::new(None) });
 
 		let mut callback = TW_CALLBACK2 {
 			CallBackProc: Self::callback as _,
@@ -207,6 +371,10 @@ impl OpenedDS {
 
 		log::debug!("Enabling TWAIN DS \"{}\"", self.name);
 
+		// A new batch starts, the outcome of the previous one no longer applies
+		*self.last_transfer.write() = None;
+		*self.close_requested.write() = false;
+
 		// Set state beforehand in case this call causes a callback to change the state further, we can roll back on error
 		self.set_state(DSState::SourceEnabled);
 
@@ -256,7 +424,8 @@ impl OpenedDS {
 		Ok(())
 	}
 
-	pub fn acquire_native_image<T, F: FnOnce(TW_HANDLE) -> T>(&self, f: F) -> Result<Option<T>, DSError> {
+	// The outcome is None if the source failed to end the transfer, it stays in Transferring and end_faulted_transfer() can be used to recover
+	pub fn acquire_native_image<T, F: FnOnce(TW_HANDLE) -> T>(&self, f: F) -> Result<(Option<T>, Option<transfer::TransferOutcome>), DSError> {
 		if self.get_state() != DSState::TransferReady {
 			return Err(DSError::InvalidState(self.get_state()));
 		}
@@ -280,27 +449,43 @@ impl OpenedDS {
 
 		let f_result = handle.map(f);
 
+		let outcome = self.end_transfer().ok();
+
+		Ok((f_result, outcome))
+	}
+
+	fn end_transfer(&self) -> Result<transfer::TransferOutcome, Response> {
 		let mut px: MaybeUninit<TW_PENDINGXFERS> = MaybeUninit::uninit();
 		let res = self.do_dsm_entry(DG_CONTROL, DAT_PENDINGXFERS, MSG_ENDXFER, px.as_mut_ptr() as _);
-		if res.is_success() {
-			let px = unsafe { px.assume_init() };
+		if !res.is_success() {
+			log::warn!("Unable to end transfer on \"{}\": {}", self.name, res);
+			*self.last_transfer.write() = None;
+			return Err(res);
+		}
 
-			log::debug!("Ended transfer on \"{}\", {} image(s) remaining", self.name, px.Count);
+		let outcome = transfer::TransferOutcome::from(unsafe { px.assume_init() });
+		*self.last_transfer.write() = Some(outcome);
 
-			if px.Count == 0 {
-				self.set_state(DSState::SourceEnabled);
-			} else {
-				self.set_state(DSState::TransferReady);
-			}
+		log::debug!("Ended transfer on \"{}\": {}", self.name, outcome);
+
+		if outcome.finished {
+			self.set_state(DSState::SourceEnabled);
 		} else {
-			log::warn!("Unable to end transfer on \"{}\": {}", self.name, res);
+			self.set_state(DSState::TransferReady);
 		}
 
-		Ok(f_result)
+		Ok(outcome)
 	}
 
 	pub fn do_dsm_entry(&self, dg: TwainUConst, dat: TwainUConst, msg: TwainUConst, data: TW_MEMREF) -> Response {
-		self.dsm.do_dsm_entry(Some(&mut self.ds_identity.write()), dg, dat, msg, data)
+		let policy = self.retry_policy.read().clone();
+		match policy {
+			Some(policy) => {
+				let wrapper = &self.dsm.dsm_entry_wrapper;
+				wrapper.do_dsm_entry_with_retry(Some(&policy), Some(&mut self.dsm.app_identity.write()), Some(&mut self.ds_identity.write()), Triplet::new(dg, dat, msg), data)
+			},
+			None => self.dsm.do_dsm_entry(Some(&mut self.ds_identity.write()), dg, dat, msg, data),
+		}
 	}
 
 	extern "C" fn callback(origin: pTW_IDENTITY, dest: pTW_IDENTITY, dg: TW_UINT32, dat: TW_UINT16, msg: TW_UINT16, data: TW_MEMREF) -> TW_UINT16 {
@@ -311,8 +496,14 @@ impl OpenedDS {
 		let message_str = || format!("{:08x}/{:04x}/{:04x} \"{}\" -> \"{}\"", dg, dat, msg, id_to_label(&origin_id), id_to_label(&dest_id));
 		log::debug!("TWAIN callback {}", message_str());
 
+		let call = interceptor::DSMCall { origin: Some(origin_id), dest: Some(dest_id), triplet: Triplet::new(dg, dat as TwainUConst, msg as TwainUConst), data };
+		self_.dsm.dsm_entry_wrapper.notify_callback(&call);
+
 		match msg as TwainUConst {
 			MSG_XFERREADY => self_.set_state(DSState::TransferReady),
+			// Events are fetched later with dispatch_device_events(), the DS doesn't expect calls back into it from here
+			MSG_DEVICEEVENT => *self_.pending_device_events.write() += 1,
+			MSG_CLOSEDSREQ | MSG_CLOSEDSOK => *self_.close_requested.write() = true,
 			_ => log::warn!("Unknown or unsupported callback message {}", message_str()),
 		}
 
@@ -327,6 +518,13 @@ impl OpenedDS {
 
 impl Drop for OpenedDS {
 	fn drop(&mut self) {
+		// A failed MSG_ENDXFER leaves the source in Transferring, it can't be closed from there
+		if self.get_state() == DSState::Transferring {
+			if let Err(err) = self.end_faulted_transfer() {
+				log::warn!("Unable to end faulted transfer on \"{}\": {}", self.name, err);
+			}
+		}
+
 		if self.get_state() == DSState::TransferReady {
 			self.reset_pending_transfers().unwrap_or_else(|err| log::warn!("Unable to reset pending transfers on \"{}\": {}", self.name, err));
 		}
@@ -344,6 +542,12 @@ impl Drop for OpenedDS {
 	}
 }
 
+impl fmt::Display for Triplet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		write!(f, "{:08x}/{:04x}/{:04x}", self.dg, self.dat, self.msg)
+	}
+}
+
 impl fmt::Display for DSState {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
 		match self {
@@ -360,6 +564,9 @@ impl fmt::Display for DSError {
 		match self {
 			Self::InvalidState(state) => write!(f, "InvalidState({})", state),
 			Self::BadResponse(res)    => write!(f, "BadResponse({})", res),
+			Self::MemoryError         => write!(f, "MemoryError"),
+			Self::BadContainer(ct)    => write!(f, "BadContainer({})", ct),
+			Self::BadImage            => write!(f, "BadImage"),
 		}
 	}
 }
@@ -0,0 +1,143 @@
+use super::{DSError, OpenedDS};
+use super::detection::*;
+use super::extimageinfo::*;
+use super::image::Image;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+pub const PATCH_CODE_DETECTION: DetectionCapabilities = DetectionCapabilities {
+	enabled: ICAP_PATCHCODEDETECTIONENABLED,
+	search_priorities: ICAP_PATCHCODESEARCHPRIORITIES,
+	search_mode: ICAP_PATCHCODESEARCHMODE,
+	max_retries: ICAP_PATCHCODEMAXRETRIES,
+	timeout: ICAP_PATCHCODETIMEOUT,
+	ext_image_info: &[TWEI_PATCHCODE],
+};
+
+pub type PatchCodeConfig = DetectionConfig;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitOptions {
+	pub separators: Vec<TW_UINT16>,
+	pub drop_separators: bool,
+}
+
+pub struct Documents<I> {
+	images: I,
+	options: SplitOptions,
+	current: Vec<Image>,
+}
+
+impl Default for SplitOptions {
+	fn default() -> Self {
+		Self {
+			separators: vec![TWPCH_PATCHT as TW_UINT16],
+			drop_separators: true,
+		}
+	}
+}
+
+pub fn patch_code_name(patch_code: TW_UINT16) -> Option<&'static str> {
+	Some(match patch_code as TwainUConst {
+		TWPCH_PATCH1 => "PATCH1",
+		TWPCH_PATCH2 => "PATCH2",
+		TWPCH_PATCH3 => "PATCH3",
+		TWPCH_PATCH4 => "PATCH4",
+		TWPCH_PATCH6 => "PATCH6",
+		TWPCH_PATCHT => "PATCHT",
+		_ => return None,
+	})
+}
+
+pub fn split_documents<I: IntoIterator<Item = Image>>(images: I, options: SplitOptions) -> Documents<I::IntoIter> {
+	Documents { images: images.into_iter(), options, current: Vec::new() }
+}
+
+impl<I: Iterator<Item = Image>> Iterator for Documents<I> {
+	type Item = Vec<Image>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for image in self.images.by_ref() {
+			let is_separator = image.patch_code().is_some_and(|p| self.options.separators.contains(&p));
+			if !is_separator {
+				self.current.push(image);
+				continue;
+			}
+
+			// A separator sheet ends the current document and, when kept, starts the next one
+			let document = std::mem::take(&mut self.current);
+			if !self.options.drop_separators {
+				self.current.push(image);
+			}
+			if !document.is_empty() {
+				return Some(document);
+			}
+		}
+
+		if self.current.is_empty() {
+			None
+		} else {
+			Some(std::mem::take(&mut self.current))
+		}
+	}
+}
+
+impl OpenedDS {
+	pub fn supported_patch_code_types(&self) -> Result<Vec<TW_UINT16>, DSError> {
+		let container = self.get_capability(ICAP_SUPPORTEDPATCHCODETYPES)?;
+		Ok(container.values().iter().filter_map(|v| v.as_u32()).map(|v| v as TW_UINT16).collect())
+	}
+
+	pub fn configure_patch_codes(&self, config: &PatchCodeConfig) -> Result<(), DSError> {
+		self.configure_detection(&PATCH_CODE_DETECTION, config)
+	}
+}
+
+impl Image {
+	pub fn patch_code(&self) -> Option<TW_UINT16> {
+		let item = self.ext_info.get(&(TWEI_PATCHCODE as TweiId))?.first()?;
+		item.as_i64().map(|p| p as TW_UINT16)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::capability::*;
+	use super::super::image::PixelFormat;
+
+	fn page(patch_code: Option<TwainUConst>) -> Image {
+		let mut image = Image::new(1, 1, PixelFormat::Gray8);
+		if let Some(p) = patch_code {
+			image.ext_info.insert(TWEI_PATCHCODE as TweiId, ExtInfo::Items(vec![ExtInfoItem::Value(CapValue::UInt32(p))]));
+		}
+		image
+	}
+
+	fn patch_codes(documents: Documents<std::vec::IntoIter<Image>>) -> Vec<Vec<Option<TW_UINT16>>> {
+		documents.map(|doc| doc.iter().map(|i| i.patch_code()).collect()).collect()
+	}
+
+	#[test]
+	fn splits_on_patch_t_and_drops_separators() {
+		let pages = vec![page(Some(TWPCH_PATCHT)), page(None), page(None), page(Some(TWPCH_PATCHT)), page(Some(TWPCH_PATCHT)), page(Some(TWPCH_PATCH2)), page(None)];
+		let docs = patch_codes(split_documents(pages, SplitOptions::default()));
+
+		assert_eq!(vec![
+			vec![None, None],
+			vec![Some(TWPCH_PATCH2 as TW_UINT16), None],
+		], docs);
+	}
+
+	#[test]
+	fn keeps_separators_at_start_of_documents() {
+		let pages = vec![page(None), page(Some(TWPCH_PATCH3)), page(None)];
+		let options = SplitOptions { separators: vec![TWPCH_PATCH3 as TW_UINT16], drop_separators: false };
+		let docs = patch_codes(split_documents(pages, options));
+
+		assert_eq!(vec![
+			vec![None],
+			vec![Some(TWPCH_PATCH3 as TW_UINT16), None],
+		], docs);
+	}
+}
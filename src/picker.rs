@@ -0,0 +1,175 @@
+use super::OpenedDSM;
+use super::identity::DataSourceInfo;
+use super::response::Response;
+
+use std::fmt;
+use std::io::{self, Write};
+
+use crossterm::{cursor, event, execute, queue, style, terminal};
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+
+#[derive(Debug, Clone)]
+pub struct PickerOptions {
+	pub prompt: String,
+	pub store_as_default: bool,
+}
+
+#[derive(Debug)]
+pub enum PickerError {
+	Twain(Response),
+	Io(io::Error),
+	NoSources,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Selection {
+	index: usize,
+	count: usize,
+}
+
+// Puts the terminal back the way it was, however the picker returns
+struct TerminalGuard;
+
+impl Default for PickerOptions {
+	fn default() -> Self {
+		Self {
+			prompt: String::from("Select a TWAIN source (arrow keys to move, Enter to select, Esc to cancel):"),
+			store_as_default: false,
+		}
+	}
+}
+
+impl Selection {
+	fn up(&mut self) {
+		self.index = if self.index == 0 { self.count - 1 } else { self.index - 1 };
+	}
+
+	fn down(&mut self) {
+		self.index = if self.index + 1 >= self.count { 0 } else { self.index + 1 };
+	}
+}
+
+impl TerminalGuard {
+	fn new() -> io::Result<Self> {
+		terminal::enable_raw_mode()?;
+		let guard = Self;
+		execute!(io::stderr(), cursor::Hide)?;
+		Ok(guard)
+	}
+}
+
+impl Drop for TerminalGuard {
+	fn drop(&mut self) {
+		execute!(io::stderr(), cursor::Show).unwrap_or_else(|err| log::warn!("Unable to show the terminal cursor: {}", err));
+		terminal::disable_raw_mode().unwrap_or_else(|err| log::warn!("Unable to disable terminal raw mode: {}", err));
+	}
+}
+
+pub fn pick_source(dsm: &OpenedDSM, options: &PickerOptions) -> Result<Option<DataSourceInfo>, PickerError> {
+	let sources = dsm.get_data_sources()?;
+	if sources.is_empty() {
+		return Err(PickerError::NoSources);
+	}
+
+	let default_id = dsm.default_source().ok().map(|ds| ds.id);
+	let index = sources.iter().position(|ds| Some(ds.id) == default_id).unwrap_or(0);
+	let selection = Selection { index, count: sources.len() };
+
+	let chosen = run_picker(&sources, selection, &options.prompt)?.map(|i| sources[i].clone());
+
+	if let Some(ds) = &chosen {
+		if options.store_as_default {
+			dsm.set_default_source(ds.identity)?;
+		}
+	}
+
+	Ok(chosen)
+}
+
+fn run_picker(sources: &[DataSourceInfo], mut selection: Selection, prompt: &str) -> io::Result<Option<usize>> {
+	let mut out = io::stderr();
+	let lines = sources.len() as u16 + 1;
+
+	let _terminal = TerminalGuard::new()?;
+
+	let result = loop {
+		draw(&mut out, sources, selection, prompt)?;
+
+		let key = match event::read()? {
+			Event::Key(key) if key.kind != KeyEventKind::Release => key,
+			_ => continue,
+		};
+
+		match key.code {
+			KeyCode::Up   | KeyCode::Char('k') => selection.up(),
+			KeyCode::Down | KeyCode::Char('j') => selection.down(),
+			KeyCode::Home => selection.index = 0,
+			KeyCode::End  => selection.index = selection.count - 1,
+			KeyCode::Enter => break Some(selection.index),
+			KeyCode::Esc | KeyCode::Char('q') => break None,
+			KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break None,
+			_ => {},
+		}
+
+		queue!(out, cursor::MoveUp(lines))?;
+	};
+
+	Ok(result)
+}
+
+fn draw<W: Write>(out: &mut W, sources: &[DataSourceInfo], selection: Selection, prompt: &str) -> io::Result<()> {
+	queue!(out, cursor::MoveToColumn(0), terminal::Clear(terminal::ClearType::FromCursorDown))?;
+	queue!(out, style::Print(prompt), style::Print("\r\n"))?;
+
+	for (i, ds) in sources.iter().enumerate() {
+		let marker = if i == selection.index { '>' } else { ' ' };
+		let line = format!("{} {} / {} {}", marker, ds.manufacturer, ds.product_name, ds.version);
+		if i == selection.index {
+			queue!(out, style::PrintStyledContent(style::Stylize::reverse(line)))?;
+		} else {
+			queue!(out, style::Print(line))?;
+		}
+		queue!(out, style::Print("\r\n"))?;
+	}
+
+	out.flush()
+}
+
+impl From<Response> for PickerError {
+	fn from(res: Response) -> Self {
+		Self::Twain(res)
+	}
+}
+
+impl From<io::Error> for PickerError {
+	fn from(err: io::Error) -> Self {
+		Self::Io(err)
+	}
+}
+
+impl fmt::Display for PickerError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Twain(res) => write!(f, "Twain({})", res),
+			Self::Io(err)    => write!(f, "Io({})", err),
+			Self::NoSources  => write!(f, "NoSources"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn selection_wraps_around() {
+		let mut sel = Selection { index: 0, count: 3 };
+		sel.up();
+		assert_eq!(2, sel.index);
+		sel.down();
+		assert_eq!(0, sel.index);
+		sel.down();
+		sel.down();
+		assert_eq!(2, sel.index);
+	}
+}
@@ -0,0 +1,208 @@
+use super::{DSError, DSState, OpenedDS};
+use super::response::*;
+use super::transfer::TransferOutcome;
+use super::twain_h::*;
+
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FeedFault {
+	PaperJam,
+	DoubleFeed,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FeedFaultEvent {
+	pub fault: FeedFault,
+	// Index of the page that failed, counted over the whole batch including resumed parts
+	pub page_index: usize,
+	pub response: Response,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RecoveryAction {
+	// The operator cleared the feeder, re-enable the source and continue the batch
+	Resume,
+	Abort,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryOptions {
+	pub max_resumes: usize,
+	// How long to wait for MSG_XFERREADY after re-enabling the source
+	pub ready_timeout: Duration,
+}
+
+#[derive(Debug)]
+pub struct RecoveredBatch<T> {
+	pub pages: Vec<T>,
+	pub faults: Vec<FeedFaultEvent>,
+	pub aborted: bool,
+	// The error that ended the batch early, the pages acquired before it are kept
+	pub error: Option<DSError>,
+}
+
+impl FeedFault {
+	pub fn from_response(res: &Response) -> Option<Self> {
+		match res.condition_code {
+			ConditionCode::PaperJam        => Some(Self::PaperJam),
+			ConditionCode::PaperDoubleFeed => Some(Self::DoubleFeed),
+			_ => None,
+		}
+	}
+
+	pub fn from_error(err: &DSError) -> Option<Self> {
+		match err {
+			DSError::BadResponse(res) => Self::from_response(res),
+			_ => None,
+		}
+	}
+}
+
+impl Default for RecoveryOptions {
+	fn default() -> Self {
+		Self {
+			max_resumes: 3,
+			ready_timeout: Duration::from_secs(60),
+		}
+	}
+}
+
+impl OpenedDS {
+	// Brings the source back to SourceEnabled after a failed transfer, dropping the pages still pending in the feeder
+	pub fn end_faulted_transfer(&self) -> Result<Option<TransferOutcome>, DSError> {
+		let mut outcome = None;
+
+		if self.get_state() == DSState::Transferring {
+			outcome = Some(self.end_transfer().map_err(DSError::BadResponse)?);
+		}
+
+		if self.get_state() == DSState::TransferReady {
+			log::debug!("Dropping pending transfers after feed fault on \"{}\"", self.name);
+
+			let mut px: MaybeUninit<TW_PENDINGXFERS> = MaybeUninit::uninit();
+			let res = self.do_dsm_entry(DG_CONTROL, DAT_PENDINGXFERS, MSG_RESET, px.as_mut_ptr() as _);
+			if !res.is_success() {
+				return Err(DSError::BadResponse(res));
+			}
+
+			let reset = TransferOutcome::from(unsafe { px.assume_init() });
+			*self.last_transfer.write() = Some(reset);
+			outcome = Some(reset);
+			self.set_state(DSState::SourceEnabled);
+		}
+
+		Ok(outcome)
+	}
+
+	// Re-enables the source with its previous user interface settings, the source stays open so negotiated capabilities are kept
+	pub fn resume_batch(&self, ready_timeout: Duration) -> Result<bool, DSError> {
+		let ui = self.ui.read().ok_or_else(|| DSError::InvalidState(self.get_state()))?;
+
+		log::debug!("Resuming batch on \"{}\"", self.name);
+
+		self.disable()?;
+		self.enable(ui)?;
+
+		Ok(self.wait_while_state(DSState::SourceEnabled, ready_timeout) == DSState::TransferReady)
+	}
+
+	// Acquires pages until the source has no more transfers, handling paper jams and double feeds on the way.
+	// on_fault is called with the source in SourceEnabled, it can block until the operator has cleared the feeder.
+	pub fn acquire_with_recovery<T, A, H>(&self, options: &RecoveryOptions, acquire: A, on_fault: H) -> RecoveredBatch<T>
+	where
+		A: FnMut(&OpenedDS) -> Result<Option<T>, DSError>,
+		H: FnMut(&FeedFaultEvent) -> RecoveryAction,
+	{
+		let mut batch = RecoveredBatch { pages: Vec::new(), faults: Vec::new(), aborted: false, error: None };
+
+		if let Err(err) = self.recover_batch(options, &mut batch, acquire, on_fault) {
+			log::warn!("Batch on \"{}\" ended after {} page(s): {}", self.name, batch.pages.len(), err);
+			batch.aborted = true;
+			batch.error = Some(err);
+		}
+
+		batch
+	}
+
+	fn recover_batch<T, A, H>(&self, options: &RecoveryOptions, batch: &mut RecoveredBatch<T>, mut acquire: A, mut on_fault: H) -> Result<(), DSError>
+	where
+		A: FnMut(&OpenedDS) -> Result<Option<T>, DSError>,
+		H: FnMut(&FeedFaultEvent) -> RecoveryAction,
+	{
+		while self.get_state() == DSState::TransferReady {
+			let err = match acquire(self) {
+				// The page is kept when the source fails to end its transfer, but the batch can't go on from Transferring
+				Ok(page) => {
+					batch.pages.extend(page);
+					if self.get_state() != DSState::Transferring {
+						continue;
+					}
+					DSError::InvalidState(DSState::Transferring)
+				},
+				Err(err) => err,
+			};
+
+			let (fault, response) = match (FeedFault::from_error(&err), err) {
+				(Some(fault), DSError::BadResponse(res)) => (fault, res),
+				(_, err) => {
+					if self.get_state() == DSState::Transferring {
+						self.end_faulted_transfer()?;
+					}
+					return Err(err);
+				},
+			};
+
+			// Cancelled transfers don't count, the index is the one the faulted page would have had
+			let page_index = batch.pages.len();
+			log::warn!("{} on \"{}\" at page {}", fault, self.name, page_index);
+			self.end_faulted_transfer()?;
+
+			let event = FeedFaultEvent { fault, page_index, response };
+			batch.faults.push(event);
+
+			// The application is told about every fault, including the one that exceeds the resume limit
+			let action = on_fault(&event);
+			if batch.faults.len() > options.max_resumes || action == RecoveryAction::Abort {
+				batch.aborted = true;
+				break;
+			}
+
+			if !self.resume_batch(options.ready_timeout)? {
+				log::warn!("Source \"{}\" didn't become ready after resuming", self.name);
+				batch.aborted = true;
+				break;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::Display for FeedFault {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::PaperJam   => write!(f, "PaperJam"),
+			Self::DoubleFeed => write!(f, "DoubleFeed"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn feed_faults_from_errors() {
+		let jam = Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::PaperJam };
+		let double_feed = Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::PaperDoubleFeed };
+		let other = Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::OperationError };
+
+		assert_eq!(Some(FeedFault::PaperJam), FeedFault::from_error(&DSError::BadResponse(jam)));
+		assert_eq!(Some(FeedFault::DoubleFeed), FeedFault::from_response(&double_feed));
+		assert_eq!(None, FeedFault::from_response(&other));
+		assert_eq!(None, FeedFault::from_error(&DSError::BadImage));
+	}
+}
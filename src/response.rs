@@ -78,6 +78,24 @@ impl ReturnCode {
 			_                     => Self::Unknown(rc)
 		}
 	}
+
+	pub fn to_rc(&self) -> TW_UINT16 {
+		(match self {
+			Self::Success          => TWRC_SUCCESS,
+			Self::Failure          => TWRC_FAILURE,
+			Self::CheckStatus      => TWRC_CHECKSTATUS,
+			Self::Cancel           => TWRC_CANCEL,
+			Self::DSEvent          => TWRC_DSEVENT,
+			Self::NotDSEvent       => TWRC_NOTDSEVENT,
+			Self::XferDone         => TWRC_XFERDONE,
+			Self::EndOfList        => TWRC_ENDOFLIST,
+			Self::InfoNotSupported => TWRC_INFONOTSUPPORTED,
+			Self::DataNotAvailable => TWRC_DATANOTAVAILABLE,
+			Self::Busy             => TWRC_BUSY,
+			Self::ScannerLocked    => TWRC_SCANNERLOCKED,
+			Self::Unknown(rc)      => return *rc,
+		}) as TW_UINT16
+	}
 }
 
 impl fmt::Display for ReturnCode {
@@ -134,6 +152,42 @@ impl ConditionCode {
 			_                      => Self::Unknown(cc)
 		}
 	}
+
+	// None when DAT_STATUS itself failed
+	pub fn to_cc(&self) -> Option<TW_UINT16> {
+		Some((match self {
+			Self::NoConditionCode(_) => return None,
+			Self::Success            => TWCC_SUCCESS,
+			Self::Bummer             => TWCC_BUMMER,
+			Self::LowMemory          => TWCC_LOWMEMORY,
+			Self::NoDS               => TWCC_NODS,
+			Self::MaxConnections     => TWCC_MAXCONNECTIONS,
+			Self::OperationError     => TWCC_OPERATIONERROR,
+			Self::BadCap             => TWCC_BADCAP,
+			Self::BadProtocol        => TWCC_BADPROTOCOL,
+			Self::BadValue           => TWCC_BADVALUE,
+			Self::SeqError           => TWCC_SEQERROR,
+			Self::BadDest            => TWCC_BADDEST,
+			Self::CapUnsupported     => TWCC_CAPUNSUPPORTED,
+			Self::CapBadOperation    => TWCC_CAPBADOPERATION,
+			Self::CapSeqError        => TWCC_CAPSEQERROR,
+			Self::Denied             => TWCC_DENIED,
+			Self::FileExists         => TWCC_FILEEXISTS,
+			Self::FileNotFound       => TWCC_FILENOTFOUND,
+			Self::NotEmpty           => TWCC_NOTEMPTY,
+			Self::PaperJam           => TWCC_PAPERJAM,
+			Self::PaperDoubleFeed    => TWCC_PAPERDOUBLEFEED,
+			Self::FileWriteError     => TWCC_FILEWRITEERROR,
+			Self::CheckDeviceOnline  => TWCC_CHECKDEVICEONLINE,
+			Self::Interlock          => TWCC_INTERLOCK,
+			Self::DamagedCorner      => TWCC_DAMAGEDCORNER,
+			Self::FocusError         => TWCC_FOCUSERROR,
+			Self::DocTooLight        => TWCC_DOCTOOLIGHT,
+			Self::DocTooDark         => TWCC_DOCTOODARK,
+			Self::NoMedia            => TWCC_NOMEDIA,
+			Self::Unknown(cc)        => return Some(*cc),
+		}) as TW_UINT16)
+	}
 }
 
 impl fmt::Display for ConditionCode {
@@ -0,0 +1,154 @@
+use super::{OpenedDS, Triplet};
+use super::response::*;
+use super::twain_h::*;
+
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+	// Total number of calls including the first one
+	pub max_attempts: u32,
+	pub initial_backoff: Duration,
+	pub max_backoff: Duration,
+	pub multiplier: f64,
+	// Only these triplets are retried, everything else is called once
+	pub triplets: Vec<Triplet>,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 5,
+			initial_backoff: Duration::from_millis(200),
+			max_backoff: Duration::from_secs(5),
+			multiplier: 2.0,
+			triplets: vec![
+				Triplet::new(DG_CONTROL, DAT_IDENTITY, MSG_OPENDS),
+				Triplet::new(DG_CONTROL, DAT_USERINTERFACE, MSG_ENABLEDS),
+			],
+		}
+	}
+}
+
+impl RetryPolicy {
+	pub fn applies_to(&self, triplet: &Triplet) -> bool {
+		self.triplets.contains(triplet)
+	}
+
+	pub fn is_retryable(res: &Response) -> bool {
+		matches!(res.return_code, ReturnCode::Busy | ReturnCode::ScannerLocked)
+	}
+
+	// Delay before the given retry, starting at 1 for the first retry
+	pub fn backoff(&self, retry: u32) -> Duration {
+		let factor = self.multiplier.max(1.0).powi(retry.saturating_sub(1) as i32);
+		self.initial_backoff.mul_f64(factor).min(self.max_backoff)
+	}
+
+	pub fn run<F: FnMut() -> Response>(&self, triplet: Triplet, mut call: F) -> Response {
+		let mut res = call();
+		if !self.applies_to(&triplet) {
+			return res;
+		}
+
+		let mut attempt = 1;
+		while Self::is_retryable(&res) && attempt < self.max_attempts {
+			let delay = self.backoff(attempt);
+			log::warn!("{} returned {}, retrying in {:?} (attempt {} of {})", triplet, res, delay, attempt + 1, self.max_attempts);
+			thread::sleep(delay);
+
+			res = call();
+			attempt += 1;
+		}
+
+		res
+	}
+}
+
+impl OpenedDS {
+	// Overrides the DSM wide policy for calls to this source, None falls back to it
+	pub fn set_retry_policy(&self, policy: Option<RetryPolicy>) {
+		*self.retry_policy.write() = policy;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::DSMEntryWrapper;
+	use super::super::interceptor::*;
+	use super::super::twain_h_ext::*;
+
+	use std::ptr;
+	use std::sync::Arc;
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	fn response(return_code: ReturnCode) -> Response {
+		Response { return_code, condition_code: ConditionCode::NoConditionCode(return_code) }
+	}
+
+	fn policy() -> RetryPolicy {
+		RetryPolicy { initial_backoff: Duration::from_millis(1), max_backoff: Duration::from_millis(2), ..Default::default() }
+	}
+
+	#[test]
+	fn retries_busy_until_success() {
+		let mut results = vec![response(ReturnCode::Success), response(ReturnCode::ScannerLocked), response(ReturnCode::Busy)];
+		let res = policy().run(Triplet::new(DG_CONTROL, DAT_IDENTITY, MSG_OPENDS), || results.pop().unwrap());
+		assert_eq!(ReturnCode::Success, res.return_code);
+		assert!(results.is_empty());
+	}
+
+	#[test]
+	fn gives_up_and_returns_last_response() {
+		let mut calls = 0;
+		let res = policy().run(Triplet::new(DG_CONTROL, DAT_USERINTERFACE, MSG_ENABLEDS), || { calls += 1; response(ReturnCode::Busy) });
+		assert_eq!(ReturnCode::Busy, res.return_code);
+		assert_eq!(5, calls);
+
+		let mut calls = 0;
+		policy().run(Triplet::new(DG_CONTROL, DAT_USERINTERFACE, MSG_DISABLEDS), || { calls += 1; response(ReturnCode::Busy) });
+		assert_eq!(1, calls);
+	}
+
+	struct ClearPolicy(Arc<DSMEntryWrapper>);
+
+	impl Interceptor for ClearPolicy {
+		fn after(&self, _call: &DSMCall, _res: &mut Response) {
+			self.0.set_retry_policy(None);
+		}
+	}
+
+	#[test]
+	fn policy_can_be_changed_during_a_call() {
+		let calls = Arc::new(AtomicUsize::new(0));
+		let counter = calls.clone();
+		let wrapper = Arc::new(DSMEntryWrapper::from_fn(move |_, _, _, dat, _, data| {
+			if dat as TwainUConst == DAT_STATUS {
+				unsafe { (*(data as pTW_STATUS)).ConditionCode = TWCC_SUCCESS as TW_UINT16 };
+				return TWRC_SUCCESS as TW_UINT16;
+			}
+			counter.fetch_add(1, Ordering::SeqCst);
+			TWRC_BUSY as TW_UINT16
+		}));
+		wrapper.set_retry_policy(Some(RetryPolicy { max_attempts: 3, ..policy() }));
+		wrapper.add_interceptor(Arc::new(ClearPolicy(wrapper.clone())));
+
+		// The policy in effect when the call started is used for all of its attempts
+		let res = wrapper.do_dsm_entry(None, None, DG_CONTROL, DAT_IDENTITY, MSG_OPENDS, ptr::null_mut());
+		assert_eq!(ReturnCode::Busy, res.return_code);
+		assert_eq!(3, calls.load(Ordering::SeqCst));
+		assert!(wrapper.retry_policy.read().is_none());
+
+		wrapper.clear_interceptors();
+	}
+
+	#[test]
+	fn backoff_is_capped() {
+		let policy = RetryPolicy { initial_backoff: Duration::from_millis(100), max_backoff: Duration::from_millis(300), ..Default::default() };
+		assert_eq!(Duration::from_millis(100), policy.backoff(1));
+		assert_eq!(Duration::from_millis(200), policy.backoff(2));
+		assert_eq!(Duration::from_millis(300), policy.backoff(3));
+	}
+}
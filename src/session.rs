@@ -0,0 +1,698 @@
+use super::{DSMEntryWrapper, Triplet};
+use super::capability::*;
+use super::data::PointerFromHandle;
+use super::deviceevent::DeviceEvent;
+use super::entrypoint::*;
+use super::image::{dib_len, BITMAPINFOHEADER_SIZE};
+use super::interceptor::*;
+use super::response::*;
+use super::transfer::TransferOutcome;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::mem;
+use std::path::Path;
+use std::ptr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+// A session log is a header line followed by one tab separated line per entry:
+//   twain2-session <version> <start time in unix seconds>
+//   call <us> <dg/dat/msg> <origin id> <dest id> <rc> <cc> <payload hex> <extra hex> <description>
+//   callback <us> <dg/dat/msg> <during call>
+// MSG_OPENDSM records the application identity as the DSM left it, its payload is the TW_IDENTITY.
+// Handles the source allocates for DAT_EXTIMAGEINFO items and DAT_ENTRYPOINT are not recorded, replay provides its own memory functions.
+pub const SESSION_MAGIC: &str = "twain2-session";
+pub const SESSION_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallRecord {
+	pub micros: u64,
+	pub triplet: Triplet,
+	pub origin_id: Option<TW_UINT32>,
+	pub dest_id: Option<TW_UINT32>,
+	pub return_code: TW_UINT16,
+	// None if DAT_STATUS failed
+	pub condition_code: Option<TW_UINT16>,
+	// The data structure after the call, for DATs with a known layout
+	pub payload: Vec<u8>,
+	// Memory the data structure points to: capability containers, native DIBs and memory transfer buffers
+	pub extra: Vec<u8>,
+	pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionEntry {
+	Call(CallRecord),
+	// Callbacks that arrive while a call is in progress are replayed at the end of the call that follows them
+	Callback { micros: u64, triplet: Triplet, during_call: bool },
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+	Io(io::Error),
+	BadHeader,
+	UnsupportedVersion(u32),
+	BadLine(usize),
+}
+
+pub struct SessionRecorder {
+	out: Mutex<Box<dyn Write + Send>>,
+	start: Instant,
+	entry_points: Mutex<Option<EntryPoints>>,
+	in_flight: AtomicUsize,
+}
+
+struct ReplayState {
+	entries: VecDeque<SessionEntry>,
+	status: Option<TW_UINT16>,
+	pending_callbacks: Vec<Triplet>,
+	// CallBackProc and RefCon registered by the application, stored as integers to keep the state Send
+	callback: Option<(usize, TW_UINTPTR)>,
+	app_identity: TW_IDENTITY,
+	ds_identity: TW_IDENTITY,
+}
+
+pub struct Replay {
+	state: Mutex<ReplayState>,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+	if !s.len().is_multiple_of(2) {
+		return None;
+	}
+	(0..s.len()).step_by(2).map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok()).collect()
+}
+
+fn parse_optional(s: &str) -> Option<Option<u32>> {
+	match s {
+		"-" => Some(None),
+		s => s.parse().ok().map(Some),
+	}
+}
+
+fn format_optional<T: fmt::Display>(value: Option<T>) -> String {
+	value.map_or("-".to_string(), |v| v.to_string())
+}
+
+fn parse_triplet(s: &str) -> Option<Triplet> {
+	let mut parts = s.split('/').map(|p| TwainUConst::from_str_radix(p, 16).ok());
+	let triplet = Triplet::new(parts.next()??, parts.next()??, parts.next()??);
+	parts.next().is_none().then_some(triplet)
+}
+
+fn read_struct<T: Copy>(bytes: &[u8]) -> Option<T> {
+	(bytes.len() == mem::size_of::<T>()).then(|| unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) })
+}
+
+unsafe fn read_bytes(p: *const u8, len: usize) -> Vec<u8> {
+	std::slice::from_raw_parts(p, len).to_vec()
+}
+
+// Data structures that are copied back as a whole on replay
+fn fixed_size(dat: TwainUConst) -> Option<usize> {
+	Some(match dat {
+		DAT_IDENTITY      => mem::size_of::<TW_IDENTITY>(),
+		DAT_PENDINGXFERS  => mem::size_of::<TW_PENDINGXFERS>(),
+		DAT_IMAGEINFO     => mem::size_of::<TW_IMAGEINFO>(),
+		DAT_IMAGELAYOUT   => mem::size_of::<TW_IMAGELAYOUT>(),
+		DAT_SETUPMEMXFER  => mem::size_of::<TW_SETUPMEMXFER>(),
+		DAT_SETUPFILEXFER => mem::size_of::<TW_SETUPFILEXFER>(),
+		DAT_DEVICEEVENT   => mem::size_of::<TW_DEVICEEVENT>(),
+		_ => return None,
+	})
+}
+
+fn ext_image_info_len(num_infos: usize) -> usize {
+	mem::size_of::<TW_EXTIMAGEINFO>() - mem::size_of::<TW_INFO>() + num_infos * mem::size_of::<TW_INFO>()
+}
+
+fn is_capability_set(msg: TwainUConst) -> bool {
+	msg == MSG_SET || msg == MSG_SETCONSTRAINT
+}
+
+// Records the data after a call. Must only be called with the data pointer the DSM was called with.
+unsafe fn capture(ep: Option<&EntryPoints>, triplet: Triplet, data: TW_MEMREF, res: &Response) -> (Vec<u8>, Vec<u8>) {
+	if data.is_null() || triplet.dat == DAT_STATUS {
+		return (Vec::new(), Vec::new());
+	}
+
+	let succeeded = matches!(res.return_code, ReturnCode::Success | ReturnCode::XferDone | ReturnCode::CheckStatus);
+	let p = data as *const u8;
+
+	if let Some(size) = fixed_size(triplet.dat) {
+		return (read_bytes(p, size), Vec::new());
+	}
+
+	match triplet.dat {
+		DAT_CAPABILITY => {
+			let payload = read_bytes(p, mem::size_of::<TW_CAPABILITY>());
+			let capability = ptr::read_unaligned(data as *const TW_CAPABILITY);
+			let extra = match ep {
+				Some(ep) if succeeded && !capability.hContainer.is_null() => PointerFromHandle::<u8>::new(ep, capability.hContainer)
+					.and_then(|c| {
+						let len = CapContainer::len_from_header(capability.ConType, std::slice::from_raw_parts(*c, CONTAINER_LEN_HEADER_SIZE))?;
+						Some(read_bytes(*c, len))
+					})
+					.unwrap_or_default(),
+				_ => Vec::new(),
+			};
+			(payload, extra)
+		},
+		DAT_IMAGENATIVEXFER => {
+			let handle = ptr::read_unaligned(data as *const TW_HANDLE);
+			let extra = match ep {
+				Some(ep) if res.return_code == ReturnCode::XferDone && !handle.is_null() => PointerFromHandle::<u8>::new(ep, handle)
+					.and_then(|dib| Some(read_bytes(*dib, dib_len(std::slice::from_raw_parts(*dib, BITMAPINFOHEADER_SIZE))?)))
+					.unwrap_or_default(),
+				_ => Vec::new(),
+			};
+			(Vec::new(), extra)
+		},
+		DAT_IMAGEMEMXFER => {
+			let xfer = ptr::read_unaligned(data as *const TW_IMAGEMEMXFER);
+			let pointer = xfer.Memory.Flags & TWMF_POINTER != 0 && !xfer.Memory.TheMem.is_null();
+			let extra = if succeeded && pointer {
+				read_bytes(xfer.Memory.TheMem as *const u8, xfer.BytesWritten.min(xfer.Memory.Length) as usize)
+			} else {
+				Vec::new()
+			};
+			(read_bytes(p, mem::size_of::<TW_IMAGEMEMXFER>()), extra)
+		},
+		DAT_EXTIMAGEINFO => {
+			let num_infos = ptr::read_unaligned(data as *const TW_UINT32) as usize;
+			(read_bytes(p, ext_image_info_len(num_infos)), Vec::new())
+		},
+		_ => (Vec::new(), Vec::new()),
+	}
+}
+
+fn describe(triplet: Triplet, payload: &[u8], extra: &[u8]) -> String {
+	match triplet.dat {
+		DAT_IDENTITY | DAT_PARENT => read_struct::<TW_IDENTITY>(payload)
+			.map(|id| { let id_num = id.Id; format!("\"{}\" id {}", tw_str32_to_string(&id.ProductName), id_num) }),
+		DAT_PENDINGXFERS => read_struct::<TW_PENDINGXFERS>(payload).map(|px| TransferOutcome::from(px).to_string()),
+		DAT_IMAGEINFO => read_struct::<TW_IMAGEINFO>(payload).map(|info| {
+			let (width, length, bpp) = (info.ImageWidth, info.ImageLength, info.BitsPerPixel);
+			format!("{}x{} {} bpp", width, length, bpp)
+		}),
+		DAT_DEVICEEVENT => read_struct::<TW_DEVICEEVENT>(payload).map(|event| DeviceEvent::from(event).to_string()),
+		DAT_CAPABILITY => read_struct::<TW_CAPABILITY>(payload).map(|capability| {
+			match CapContainer::from_bytes(capability.ConType, extra) {
+				Some(container) => format!("{} {}", cap_label(capability.Cap), container),
+				None => cap_label(capability.Cap),
+			}
+		}),
+		DAT_IMAGENATIVEXFER if !extra.is_empty() => Some(format!("DIB {} bytes", extra.len())),
+		DAT_IMAGEMEMXFER => read_struct::<TW_IMAGEMEMXFER>(payload).map(|xfer| {
+			let rows = xfer.Rows;
+			format!("{} rows, {} bytes", rows, extra.len())
+		}),
+		DAT_EXTIMAGEINFO => read_struct::<TW_UINT32>(&payload[..payload.len().min(4)]).map(|n| format!("{} entries", n)),
+		_ => None,
+	}.unwrap_or_default()
+}
+
+impl SessionRecorder {
+	pub fn new<W: Write + Send + 'static>(out: W) -> io::Result<Arc<Self>> {
+		let mut out: Box<dyn Write + Send> = Box::new(out);
+		let started = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+		writeln!(out, "{}\t{}\t{}", SESSION_MAGIC, SESSION_VERSION, started)?;
+		out.flush()?;
+
+		Ok(Arc::new(Self {
+			out: Mutex::new(out),
+			start: Instant::now(),
+			entry_points: Mutex::new(EntryPoints::os_default()),
+			in_flight: AtomicUsize::new(0),
+		}))
+	}
+
+	pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Arc<Self>> {
+		Self::new(BufWriter::new(File::create(path)?))
+	}
+
+	fn micros(&self) -> u64 {
+		self.start.elapsed().as_micros() as u64
+	}
+
+	fn write_entry(&self, entry: &SessionEntry) {
+		let mut out = self.out.lock();
+		if let Err(err) = writeln!(out, "{}", entry).and_then(|_| out.flush()) {
+			log::warn!("Unable to write session entry: {}", err);
+		}
+	}
+}
+
+impl Interceptor for SessionRecorder {
+	fn before(&self, _call: &DSMCall) -> Option<Response> {
+		self.in_flight.fetch_add(1, Ordering::SeqCst);
+		None
+	}
+
+	fn after(&self, call: &DSMCall, res: &mut Response) {
+		self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+		let triplet = call.triplet;
+		if triplet.dat == DAT_ENTRYPOINT && triplet.msg == MSG_GET && res.is_success() && !call.data.is_null() {
+			let ep = unsafe { ptr::read_unaligned(call.data as *const TW_ENTRYPOINT) };
+			if let Some(ep) = EntryPoints::from_tw_entrypoint(ep) {
+				*self.entry_points.lock() = Some(ep);
+			}
+		}
+
+		let (payload, extra) = match call.origin {
+			// The DSM writes to the application identity, there is no data structure
+			Some(origin) if triplet.dat == DAT_PARENT && triplet.msg == MSG_OPENDSM => unsafe {
+				(read_bytes(&origin as *const TW_IDENTITY as *const u8, mem::size_of::<TW_IDENTITY>()), Vec::new())
+			},
+			_ => unsafe { capture(self.entry_points.lock().as_ref(), triplet, call.data, res) },
+		};
+		let description = describe(triplet, &payload, &extra);
+
+		self.write_entry(&SessionEntry::Call(CallRecord {
+			micros: self.micros(),
+			triplet,
+			origin_id: call.origin.map(|id| id.Id),
+			dest_id: call.dest.map(|id| id.Id),
+			return_code: res.return_code.to_rc(),
+			condition_code: res.condition_code.to_cc(),
+			payload,
+			extra,
+			description,
+		}));
+	}
+
+	fn callback(&self, call: &DSMCall) {
+		let during_call = self.in_flight.load(Ordering::SeqCst) > 0;
+		self.write_entry(&SessionEntry::Callback { micros: self.micros(), triplet: call.triplet, during_call });
+	}
+}
+
+unsafe fn replay_handle(bytes: &[u8]) -> TW_HANDLE {
+	let handle = (EntryPoints::host().allocate)(bytes.len() as TW_UINT32);
+	if !handle.is_null() {
+		ptr::copy_nonoverlapping(bytes.as_ptr(), handle as *mut u8, bytes.len());
+	}
+	handle
+}
+
+impl Replay {
+	pub fn parse<R: BufRead>(reader: R) -> Result<Arc<Self>, SessionError> {
+		let mut lines = reader.lines();
+
+		let header = lines.next().ok_or(SessionError::BadHeader)??;
+		let mut fields = header.split('\t');
+		if fields.next() != Some(SESSION_MAGIC) {
+			return Err(SessionError::BadHeader);
+		}
+		let version: u32 = fields.next().and_then(|v| v.parse().ok()).ok_or(SessionError::BadHeader)?;
+		if version != SESSION_VERSION {
+			return Err(SessionError::UnsupportedVersion(version));
+		}
+
+		let mut entries = VecDeque::new();
+		for (i, line) in lines.enumerate() {
+			let line = line?;
+			if line.is_empty() {
+				continue;
+			}
+			entries.push_back(line.parse().map_err(|_| SessionError::BadLine(i + 2))?);
+		}
+
+		Ok(Arc::new(Self {
+			state: Mutex::new(ReplayState {
+				entries,
+				status: None,
+				pending_callbacks: Vec::new(),
+				callback: None,
+				app_identity: Default::default(),
+				ds_identity: Default::default(),
+			}),
+		}))
+	}
+
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Arc<Self>, SessionError> {
+		Self::parse(BufReader::new(File::open(path)?))
+	}
+
+	pub fn wrapper(self: &Arc<Self>) -> DSMEntryWrapper {
+		let replay = self.clone();
+		DSMEntryWrapper::from_fn(move |origin, dest, dg, dat, msg, data| {
+			unsafe { replay.entry(origin, dest, Triplet::new(dg as TwainUConst, dat as TwainUConst, msg as TwainUConst), data) }
+		})
+	}
+
+	// Calls left in the log
+	pub fn remaining(&self) -> usize {
+		self.state.lock().entries.iter().filter(|e| matches!(e, SessionEntry::Call(_))).count()
+	}
+
+	unsafe fn entry(&self, origin: pTW_IDENTITY, dest: pTW_IDENTITY, triplet: Triplet, data: TW_MEMREF) -> TW_UINT16 {
+		// DSMEntryWrapper asks for the status right after every call
+		if triplet == Triplet::new(DG_CONTROL, DAT_STATUS, MSG_GET) {
+			return self.status(data);
+		}
+
+		let mut state = self.state.lock();
+		if !origin.is_null() {
+			state.app_identity = *origin;
+		}
+		if !dest.is_null() {
+			state.ds_identity = *dest;
+		}
+
+		while let Some(SessionEntry::Callback { triplet, .. }) = state.entries.front() {
+			let triplet = *triplet;
+			state.pending_callbacks.push(triplet);
+			state.entries.pop_front();
+		}
+
+		let record = match state.entries.pop_front() {
+			Some(SessionEntry::Call(record)) if record.triplet == triplet => record,
+			next => {
+				log::warn!("Replay got {} but expected {:?}", triplet, next.as_ref().map(|e| e.to_string()));
+				if let Some(next) = next {
+					state.entries.push_front(next);
+				}
+				state.status = Some(TWCC_SEQERROR as TW_UINT16);
+				return TWRC_FAILURE as TW_UINT16;
+			},
+		};
+
+		if let Some(id) = record.origin_id.filter(|_| !origin.is_null()) {
+			(*origin).Id = id;
+		}
+		// DF_DSM2 decides whether the application asks for DAT_ENTRYPOINT next
+		if let Some(identity) = read_struct::<TW_IDENTITY>(&record.payload).filter(|_| triplet.dat == DAT_PARENT && !origin.is_null()) {
+			(*origin).SupportedGroups = identity.SupportedGroups;
+		}
+		if let Some(id) = record.dest_id.filter(|_| !dest.is_null()) {
+			(*dest).Id = id;
+		}
+
+		if !data.is_null() {
+			Self::apply(&mut state, &record, data);
+		}
+
+		state.status = record.condition_code;
+		record.return_code
+	}
+
+	unsafe fn apply(state: &mut ReplayState, record: &CallRecord, data: TW_MEMREF) {
+		let triplet = record.triplet;
+		let succeeded = record.return_code == TWRC_SUCCESS as TW_UINT16;
+
+		match triplet.dat {
+			DAT_ENTRYPOINT if succeeded => {
+				ptr::write_unaligned(data as *mut TW_ENTRYPOINT, host_tw_entrypoint());
+			},
+			DAT_CALLBACK2 if triplet.msg == MSG_REGISTER_CALLBACK => {
+				let callback = ptr::read_unaligned(data as *const TW_CALLBACK2);
+				state.callback = Some((callback.CallBackProc as usize, callback.RefCon));
+			},
+			DAT_CAPABILITY if !is_capability_set(triplet.msg) && !record.extra.is_empty() => {
+				if let Some(mut capability) = read_struct::<TW_CAPABILITY>(&record.payload) {
+					capability.hContainer = replay_handle(&record.extra);
+					ptr::write_unaligned(data as *mut TW_CAPABILITY, capability);
+				}
+			},
+			DAT_IMAGENATIVEXFER if !record.extra.is_empty() => {
+				ptr::write_unaligned(data as *mut TW_HANDLE, replay_handle(&record.extra));
+			},
+			DAT_IMAGEMEMXFER => {
+				let app_xfer = ptr::read_unaligned(data as *const TW_IMAGEMEMXFER);
+				if let Some(mut xfer) = read_struct::<TW_IMAGEMEMXFER>(&record.payload) {
+					let memory = app_xfer.Memory;
+					if memory.Flags & TWMF_POINTER != 0 && !memory.TheMem.is_null() {
+						let len = record.extra.len().min(memory.Length as usize);
+						ptr::copy_nonoverlapping(record.extra.as_ptr(), memory.TheMem as *mut u8, len);
+					}
+					xfer.Memory = memory;
+					ptr::write_unaligned(data as *mut TW_IMAGEMEMXFER, xfer);
+				}
+			},
+			DAT_EXTIMAGEINFO => {
+				let num_infos = ptr::read_unaligned(data as *const TW_UINT32) as usize;
+				let len = record.payload.len().min(ext_image_info_len(num_infos));
+				ptr::copy_nonoverlapping(record.payload.as_ptr(), data as *mut u8, len);
+
+				// Items the source kept in its own handles weren't recorded
+				let infos = (data as *mut u8).add(ext_image_info_len(0)) as *mut TW_INFO;
+				for i in 0..num_infos {
+					let mut info = ptr::read_unaligned(infos.add(i));
+					let size = item_size(info.ItemType).unwrap_or(usize::MAX).saturating_mul(info.NumItems as usize);
+					let in_handle = info.ItemType as TwainUConst == TWTY_HANDLE || size > mem::size_of::<TW_UINTPTR>();
+					if info.__bindgen_anon_1.ReturnCode == TWRC_SUCCESS as TW_UINT16 && info.NumItems > 0 && in_handle {
+						info.__bindgen_anon_1.ReturnCode = TWRC_DATANOTAVAILABLE as TW_UINT16;
+						info.Item = 0;
+						ptr::write_unaligned(infos.add(i), info);
+					}
+				}
+			},
+			dat => if let Some(size) = fixed_size(dat).filter(|size| *size == record.payload.len()) {
+				ptr::copy_nonoverlapping(record.payload.as_ptr(), data as *mut u8, size);
+			},
+		}
+	}
+
+	unsafe fn status(&self, data: TW_MEMREF) -> TW_UINT16 {
+		let (status, callbacks, callback, ds_identity, app_identity) = {
+			let mut state = self.state.lock();
+			let mut callbacks = mem::take(&mut state.pending_callbacks);
+			while let Some(SessionEntry::Callback { triplet, during_call: false, .. }) = state.entries.front() {
+				callbacks.push(*triplet);
+				state.entries.pop_front();
+			}
+			(state.status.take(), callbacks, state.callback, state.ds_identity, state.app_identity)
+		};
+
+		let rc = match status {
+			Some(cc) => {
+				if !data.is_null() {
+					ptr::write_unaligned(ptr::addr_of_mut!((*(data as pTW_STATUS)).ConditionCode), cc);
+				}
+				TWRC_SUCCESS
+			},
+			None => TWRC_FAILURE,
+		};
+
+		// Fired without holding the state, the application may call back into the DSM
+		if let Some((proc_address, ref_con)) = callback {
+			let proc: unsafe extern "C" fn(pTW_IDENTITY, pTW_IDENTITY, TW_UINT32, TW_UINT16, TW_UINT16, TW_MEMREF) -> TW_UINT16 = mem::transmute(proc_address);
+			let (mut origin, mut dest) = (ds_identity, app_identity);
+			for triplet in callbacks {
+				proc(&mut origin, &mut dest, triplet.dg as TW_UINT32, triplet.dat as TW_UINT16, triplet.msg as TW_UINT16, ref_con as TW_MEMREF);
+			}
+		} else if !callbacks.is_empty() {
+			log::warn!("Dropping {} replayed callbacks, no callback registered", callbacks.len());
+		}
+
+		rc as TW_UINT16
+	}
+}
+
+impl std::str::FromStr for SessionEntry {
+	type Err = ();
+
+	fn from_str(line: &str) -> Result<Self, Self::Err> {
+		let fields: Vec<&str> = line.splitn(10, '\t').collect();
+		let micros = fields.get(1).and_then(|m| m.parse().ok()).ok_or(())?;
+		let triplet = fields.get(2).and_then(|t| parse_triplet(t)).ok_or(())?;
+
+		match (fields[0], fields.len()) {
+			("callback", 4) => Ok(Self::Callback { micros, triplet, during_call: fields[3] == "1" }),
+			("call", 10) => Ok(Self::Call(CallRecord {
+				micros,
+				triplet,
+				origin_id: parse_optional(fields[3]).ok_or(())?,
+				dest_id: parse_optional(fields[4]).ok_or(())?,
+				return_code: fields[5].parse().map_err(|_| ())?,
+				condition_code: parse_optional(fields[6]).ok_or(())?.map(|cc| cc as TW_UINT16),
+				payload: from_hex(fields[7]).ok_or(())?,
+				extra: from_hex(fields[8]).ok_or(())?,
+				description: fields[9].to_string(),
+			})),
+			_ => Err(()),
+		}
+	}
+}
+
+impl fmt::Display for SessionEntry {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Call(record) => write!(f, "call\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+				record.micros,
+				record.triplet,
+				format_optional(record.origin_id),
+				format_optional(record.dest_id),
+				record.return_code,
+				format_optional(record.condition_code),
+				to_hex(&record.payload),
+				to_hex(&record.extra),
+				record.description.replace(['\t', '\n', '\r'], " "),
+			),
+			Self::Callback { micros, triplet, during_call } => write!(f, "callback\t{}\t{}\t{}", micros, triplet, *during_call as u8),
+		}
+	}
+}
+
+impl From<io::Error> for SessionError {
+	fn from(err: io::Error) -> Self {
+		Self::Io(err)
+	}
+}
+
+impl fmt::Display for SessionError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		match self {
+			Self::Io(err)                     => write!(f, "Io({})", err),
+			Self::BadHeader                   => write!(f, "BadHeader"),
+			Self::UnsupportedVersion(version) => write!(f, "UnsupportedVersion({})", version),
+			Self::BadLine(line)               => write!(f, "BadLine({})", line),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use super::super::{DSState, OpenedDS, OpenedDSM};
+	use super::super::fake::*;
+	use super::super::image::Image;
+
+	#[derive(Clone)]
+	struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+	impl Write for SharedBuffer {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			self.0.lock().extend_from_slice(buf);
+			Ok(buf.len())
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	extern "C" fn fake_dsm_entry(_origin: pTW_IDENTITY, _dest: pTW_IDENTITY, _dg: TW_UINT32, dat: TW_UINT16, msg: TW_UINT16, data: TW_MEMREF) -> TW_UINT16 {
+		match (dat as TwainUConst, msg as TwainUConst) {
+			(DAT_STATUS, _) => unsafe { (*(data as pTW_STATUS)).ConditionCode = TWCC_SUCCESS as TW_UINT16 },
+			(DAT_IDENTITY, MSG_GETFIRST) => unsafe {
+				let identity = &mut *(data as pTW_IDENTITY);
+				identity.Id = 7;
+				identity.ProductName = tw_str32("Fake Scanner");
+			},
+			(DAT_IDENTITY, MSG_GETNEXT) => return TWRC_ENDOFLIST as TW_UINT16,
+			_ => {},
+		}
+		TWRC_SUCCESS as TW_UINT16
+	}
+
+	fn list_sources(wrapper: DSMEntryWrapper) -> Vec<String> {
+		let app_identity = TW_IDENTITY { SupportedGroups: DG_CONTROL | DG_IMAGE, ..Default::default() };
+		let dsm = OpenedDSM::new(Arc::new(wrapper), app_identity).unwrap();
+		dsm.get_data_sources().unwrap().into_iter().map(|ds| ds.product_name).collect()
+	}
+
+	#[test]
+	fn records_and_replays_session() {
+		let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+
+		let wrapper = DSMEntryWrapper::from_dsmentryproc(Some(fake_dsm_entry)).unwrap();
+		wrapper.add_interceptor(SessionRecorder::new(buffer.clone()).unwrap());
+		assert_eq!(vec!["Fake Scanner".to_string()], list_sources(wrapper));
+
+		let log = buffer.0.lock().clone();
+		let replay = Replay::parse(&log[..]).unwrap();
+		assert_eq!(4, replay.remaining());
+		assert_eq!(vec!["Fake Scanner".to_string()], list_sources(replay.wrapper()));
+		assert_eq!(0, replay.remaining());
+	}
+
+	// Reads a capability, then enables the source and transfers its only page
+	fn scan_page(ds: Box<OpenedDS>) -> (CapContainer, Image) {
+		let container = ds.get_capability(ICAP_XRESOLUTION).unwrap();
+		ds.enable(no_ui()).unwrap();
+		assert_eq!(DSState::TransferReady, ds.get_state());
+		let image = ds.acquire_image().unwrap().unwrap();
+		assert_eq!(DSState::SourceEnabled, ds.get_state());
+		(container, image)
+	}
+
+	#[test]
+	fn replays_capabilities_callbacks_and_native_transfers() {
+		let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+
+		let (wrapper, source) = fake_dsm();
+		let resolutions = CapContainer::Enumeration { items: vec![CapValue::Fix32(150.0), CapValue::Fix32(300.0)], current_index: 1, default_index: 0 };
+		source.lock().capabilities.insert(ICAP_XRESOLUTION as TW_UINT16, (resolutions.clone(), true));
+		wrapper.add_interceptor(SessionRecorder::new(buffer.clone()).unwrap());
+		let (container, image) = scan_page(open_fake_source(wrapper));
+		assert_eq!(resolutions, container);
+
+		let log = buffer.0.lock().clone();
+		let replay = Replay::parse(&log[..]).unwrap();
+		assert!(String::from_utf8_lossy(&log).contains("callback\t"));
+
+		assert_eq!((container, image), scan_page(open_fake_source(replay.wrapper())));
+		assert_eq!(0, replay.remaining());
+	}
+
+	#[test]
+	fn replay_restores_groups_set_by_the_dsm() {
+		let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+
+		// open_fake_source() doesn't set DF_DSM2, the fake DSM does on MSG_OPENDSM
+		let (wrapper, _) = fake_dsm();
+		wrapper.add_interceptor(SessionRecorder::new(buffer.clone()).unwrap());
+		let ds = open_fake_source(wrapper);
+		assert_ne!(0, ds.dsm.app_identity.read().SupportedGroups & DF_DSM2);
+		drop(ds);
+
+		let log = buffer.0.lock().clone();
+		let replay = Replay::parse(&log[..]).unwrap();
+		let ds = open_fake_source(replay.wrapper());
+		assert_ne!(0, ds.dsm.app_identity.read().SupportedGroups & DF_DSM2);
+		drop(ds);
+		assert_eq!(0, replay.remaining());
+	}
+
+	#[test]
+	fn session_entry_round_trip() {
+		let entries = [
+			SessionEntry::Call(CallRecord {
+				micros: 1500,
+				triplet: Triplet::new(DG_CONTROL, DAT_PENDINGXFERS, MSG_ENDXFER),
+				origin_id: Some(1),
+				dest_id: None,
+				return_code: TWRC_FAILURE as TW_UINT16,
+				condition_code: Some(TWCC_SEQERROR as TW_UINT16),
+				payload: vec![0, 1, 0xff],
+				extra: Vec::new(),
+				description: "with\ttab".to_string(),
+			}),
+			SessionEntry::Callback { micros: 2000, triplet: Triplet::new(DG_CONTROL, DAT_NULL, MSG_XFERREADY), during_call: true },
+		];
+
+		for entry in entries {
+			let parsed: SessionEntry = entry.to_string().parse().unwrap();
+			match (&parsed, &entry) {
+				(SessionEntry::Call(parsed), SessionEntry::Call(entry)) => assert_eq!(CallRecord { description: "with tab".to_string(), ..entry.clone() }, *parsed),
+				_ => assert_eq!(entry, parsed),
+			}
+		}
+
+		assert!(matches!(Replay::parse(&b"twain2-session\t2\t0\n"[..]), Err(SessionError::UnsupportedVersion(2))));
+	}
+}
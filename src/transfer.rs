@@ -0,0 +1,368 @@
+use super::{DSError, DSState, OpenedDS};
+use super::capability::*;
+use super::data::PointerFromHandle;
+use super::entrypoint::EntryPoints;
+use super::image::*;
+use super::response::*;
+use super::twain_h::*;
+use super::twain_h_ext::*;
+
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::path::Path;
+use std::ptr;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TransferOutcome {
+	pub count: i32,
+	pub end_of_job: TW_UINT32,
+	pub finished: bool,
+}
+
+impl TransferOutcome {
+	// Sources that can't tell how many images are left (e.g. feeders) report -1
+	pub fn is_count_known(&self) -> bool {
+		self.count >= 0
+	}
+}
+
+impl From<TW_PENDINGXFERS> for TransferOutcome {
+	fn from(px: TW_PENDINGXFERS) -> Self {
+		let count = px.Count as TW_INT16 as i32;
+		Self {
+			count,
+			end_of_job: unsafe { px.__bindgen_anon_1.EOJ },
+			finished: count == 0,
+		}
+	}
+}
+
+fn decode_native(ep: &EntryPoints, handle: TW_HANDLE) -> Result<Image, DSError> {
+	let p = PointerFromHandle::<u8>::new(ep, handle).ok_or(DSError::MemoryError)?;
+	let header = unsafe { std::slice::from_raw_parts(*p, BITMAPINFOHEADER_SIZE) };
+	let len = dib_len(header).ok_or(DSError::BadImage)?;
+	let dib = unsafe { std::slice::from_raw_parts(*p, len) };
+	Image::from_dib(dib).ok_or(DSError::BadImage)
+}
+
+// Copies one uncompressed strip into the image, every value supplied by the source is checked before it is used
+fn copy_strip(image: &mut Image, buffer: &[u8], xfer: &TW_IMAGEMEMXFER, can_grow: bool) -> Result<(), DSError> {
+	if xfer.Compression as TwainUConst != TWCP_NONE {
+		return Err(DSError::BadImage);
+	}
+
+	let stride = image.stride();
+	let bpp = image.format.bits_per_pixel() as usize;
+	let written = (xfer.BytesWritten as usize).min(buffer.len());
+	let (rows, bytes_per_row) = (xfer.Rows as usize, xfer.BytesPerRow as usize);
+	let (x_offset, y_offset) = ((xfer.XOffset as usize).saturating_mul(bpp) / 8, xfer.YOffset as usize);
+
+	let row_len = bytes_per_row
+		.min((xfer.Columns as usize).saturating_mul(bpp).div_ceil(8))
+		.min(stride.checked_sub(x_offset).ok_or(DSError::BadImage)?);
+	if rows == 0 || row_len == 0 {
+		return Ok(());
+	}
+
+	let strip_len = (rows - 1).checked_mul(bytes_per_row).and_then(|n| n.checked_add(row_len)).ok_or(DSError::BadImage)?;
+	if strip_len > written {
+		return Err(DSError::BadImage);
+	}
+
+	// Sources that don't know the length up front let the image grow, others must stay inside it
+	let end_row = y_offset.checked_add(rows).ok_or(DSError::BadImage)?;
+	let needed = end_row.checked_mul(stride).ok_or(DSError::BadImage)?;
+	if image.data.len() < needed {
+		if !can_grow {
+			return Err(DSError::BadImage);
+		}
+		image.data.resize(needed, 0);
+		image.height = end_row as u32;
+	}
+
+	for r in 0..rows {
+		let src = &buffer[r * bytes_per_row..r * bytes_per_row + row_len];
+		let dst = (y_offset + r) * stride + x_offset;
+		image.data[dst..dst + row_len].copy_from_slice(src);
+	}
+
+	Ok(())
+}
+
+fn pixel_format(info: &TW_IMAGEINFO) -> Option<PixelFormat> {
+	match (info.PixelType as TwainUConst, info.BitsPerPixel) {
+		(TWPT_BW, 1)    => Some(PixelFormat::BlackWhite),
+		(TWPT_GRAY, 8)  => Some(PixelFormat::Gray8),
+		(TWPT_RGB, 24)  => Some(PixelFormat::Rgb24),
+		_ => None,
+	}
+}
+
+impl OpenedDS {
+	pub fn set_transfer_mechanism(&self, mechanism: TwainUConst) -> Result<(), DSError> {
+		self.set_capability_value(ICAP_XFERMECH, CapValue::UInt16(mechanism as TW_UINT16))
+	}
+
+	pub fn image_info(&self) -> Result<TW_IMAGEINFO, DSError> {
+		if self.get_state() != DSState::TransferReady && self.get_state() != DSState::Transferring {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let mut info: MaybeUninit<TW_IMAGEINFO> = MaybeUninit::uninit();
+		let res = self.do_dsm_entry(DG_IMAGE, DAT_IMAGEINFO, MSG_GET, info.as_mut_ptr() as _);
+		if !res.is_success() {
+			return Err(DSError::BadResponse(res));
+		}
+
+		Ok(unsafe { info.assume_init() })
+	}
+
+	// The image is returned even if the source fails to end the transfer, it is then left in Transferring until end_faulted_transfer()
+	pub fn acquire_image(&self) -> Result<Option<Image>, DSError> {
+		let ep = self.dsm.entry_points.as_ref().ok_or(DSError::MemoryError)?;
+
+		let (image, _) = self.acquire_native_image(|handle| {
+			let image = decode_native(ep, handle);
+			(ep.free)(handle);
+			image.map(|mut image| {
+				image.ext_info = self.attached_ext_image_info();
+				image.blank = self.detect_blank(&image);
+				image
+			})
+		})?;
+
+		image.transpose()
+	}
+
+	pub fn acquire_memory_image(&self) -> Result<Option<Image>, DSError> {
+		if self.get_state() != DSState::TransferReady {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let info = self.image_info()?;
+		let format = pixel_format(&info).ok_or(DSError::BadImage)?;
+
+		let mut setup: MaybeUninit<TW_SETUPMEMXFER> = MaybeUninit::uninit();
+		let res = self.do_dsm_entry(DG_CONTROL, DAT_SETUPMEMXFER, MSG_GET, setup.as_mut_ptr() as _);
+		if !res.is_success() {
+			return Err(DSError::BadResponse(res));
+		}
+		let setup = unsafe { setup.assume_init() };
+
+		let mut buffer = vec![0u8; setup.Preferred.max(setup.MinBufSize) as usize];
+		let mut image = Image::new(info.ImageWidth as u32, info.ImageLength.max(0) as u32, format);
+		image.x_resolution = fix32_to_f64(info.XResolution);
+		image.y_resolution = fix32_to_f64(info.YResolution);
+
+		loop {
+			let mut xfer = TW_IMAGEMEMXFER {
+				Compression: TWON_DONTCARE16 as TW_UINT16,
+				BytesPerRow: TWON_DONTCARE32,
+				Columns: TWON_DONTCARE32,
+				Rows: TWON_DONTCARE32,
+				XOffset: TWON_DONTCARE32,
+				YOffset: TWON_DONTCARE32,
+				BytesWritten: TWON_DONTCARE32,
+				Memory: TW_MEMORY {
+					Flags: TWMF_APPOWNS | TWMF_POINTER,
+					Length: buffer.len() as TW_UINT32,
+					TheMem: buffer.as_mut_ptr() as _,
+				},
+			};
+
+			let res = self.do_dsm_entry(DG_IMAGE, DAT_IMAGEMEMXFER, MSG_GET, &mut xfer as *mut TW_IMAGEMEMXFER as _);
+			match res.return_code {
+				ReturnCode::Success | ReturnCode::XferDone => {
+					self.set_state(DSState::Transferring);
+
+					if let Err(err) = copy_strip(&mut image, &buffer, &xfer, info.ImageLength < 0) {
+						log::warn!("Bad memory transfer strip on \"{}\"", self.name);
+						self.end_transfer().ok();
+						return Err(err);
+					}
+
+					if res.return_code == ReturnCode::XferDone {
+						log::debug!("Acquired memory image on \"{}\"", self.name);
+						image.ext_info = self.attached_ext_image_info();
+						image.blank = self.detect_blank(&image);
+						// The image is complete even if the source fails to end the transfer
+						self.end_transfer().ok();
+						return Ok(Some(image));
+					}
+				},
+				ReturnCode::Cancel => {
+					log::debug!("Acquire memory image cancelled on \"{}\"", self.name);
+					self.set_state(DSState::Transferring);
+					self.end_transfer().ok();
+					return Ok(None);
+				},
+				_ => {
+					if self.get_state() == DSState::Transferring {
+						self.end_transfer().ok();
+					}
+					return Err(DSError::BadResponse(res));
+				},
+			}
+		}
+	}
+
+	pub fn setup_file_transfer<P: AsRef<Path>>(&self, path: P, format: TwainUConst) -> Result<(), DSError> {
+		if self.get_state() == DSState::Transferring {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let mut setup = TW_SETUPFILEXFER {
+			FileName: tw_str255(path.as_ref().to_string_lossy()),
+			Format: format as TW_UINT16,
+			VRefNum: 0,
+		};
+
+		let res = self.do_dsm_entry(DG_CONTROL, DAT_SETUPFILEXFER, MSG_SET, &mut setup as *mut TW_SETUPFILEXFER as _);
+		if !res.is_success() {
+			return Err(DSError::BadResponse(res));
+		}
+
+		Ok(())
+	}
+
+	pub fn acquire_file_image(&self) -> Result<bool, DSError> {
+		if self.get_state() != DSState::TransferReady {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let res = self.do_dsm_entry(DG_IMAGE, DAT_IMAGEFILEXFER, MSG_GET, ptr::null_mut());
+		let done = match res.return_code {
+			ReturnCode::XferDone => {
+				log::debug!("Acquired file image on \"{}\"", self.name);
+				true
+			},
+			ReturnCode::Cancel => {
+				log::debug!("Acquire file image cancelled on \"{}\"", self.name);
+				false
+			},
+			_ => return Err(DSError::BadResponse(res)),
+		};
+
+		self.set_state(DSState::Transferring);
+		// The file has been written even if the source fails to end the transfer
+		self.end_transfer().ok();
+
+		Ok(done)
+	}
+
+	pub fn pending_transfers(&self) -> Result<TransferOutcome, DSError> {
+		let state = self.get_state();
+		if state != DSState::SourceEnabled && state != DSState::TransferReady && state != DSState::Transferring {
+			return Err(DSError::InvalidState(state));
+		}
+
+		let mut px: MaybeUninit<TW_PENDINGXFERS> = MaybeUninit::uninit();
+		let res = self.do_dsm_entry(DG_CONTROL, DAT_PENDINGXFERS, MSG_GET, px.as_mut_ptr() as _);
+		if !res.is_success() {
+			return Err(DSError::BadResponse(res));
+		}
+
+		Ok(TransferOutcome::from(unsafe { px.assume_init() }))
+	}
+
+	// Stops the feeder after the pages already in the paper path, which still have to be transferred.
+	// Returns None if the source doesn't support stopping the feeder.
+	pub fn stop_feeder(&self) -> Result<Option<TransferOutcome>, DSError> {
+		if self.get_state() != DSState::TransferReady {
+			return Err(DSError::InvalidState(self.get_state()));
+		}
+
+		let mut px: MaybeUninit<TW_PENDINGXFERS> = MaybeUninit::uninit();
+		let res = self.do_dsm_entry(DG_CONTROL, DAT_PENDINGXFERS, MSG_STOPFEEDER, px.as_mut_ptr() as _);
+		match res {
+			Response { return_code: ReturnCode::Success, .. } => {},
+			Response { return_code: ReturnCode::Failure, condition_code: ConditionCode::BadProtocol } => {
+				log::warn!("Stopping the feeder is not supported on \"{}\"", self.name);
+				return Ok(None);
+			},
+			res => return Err(DSError::BadResponse(res)),
+		}
+
+		let outcome = TransferOutcome::from(unsafe { px.assume_init() });
+		log::debug!("Stopped feeder on \"{}\": {}", self.name, outcome);
+
+		if outcome.finished {
+			self.set_state(DSState::SourceEnabled);
+		}
+
+		Ok(Some(outcome))
+	}
+
+	pub fn last_transfer(&self) -> Option<TransferOutcome> {
+		*self.last_transfer.read()
+	}
+}
+
+impl fmt::Display for TransferOutcome {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		if self.is_count_known() {
+			write!(f, "{} pending", self.count)?;
+		} else {
+			write!(f, "unknown pending")?;
+		}
+		if self.end_of_job != TWEJ_NONE {
+			write!(f, ", end of job {}", self.end_of_job)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn pending(count: TW_UINT16, eoj: TW_UINT32) -> TW_PENDINGXFERS {
+		TW_PENDINGXFERS { Count: count, __bindgen_anon_1: TW_PENDINGXFERS__bindgen_ty_1 { EOJ: eoj } }
+	}
+
+	#[test]
+	fn transfer_outcome_from_pending_xfers() {
+		let unknown = TransferOutcome::from(pending(0xffff, TWEJ_NONE));
+		assert_eq!(-1, unknown.count);
+		assert!(!unknown.is_count_known() && !unknown.finished);
+		assert_eq!("unknown pending", unknown.to_string());
+
+		let done = TransferOutcome::from(pending(0, TWEJ_PATCHT));
+		assert!(done.finished);
+		assert_eq!("0 pending, end of job 7", done.to_string());
+	}
+
+	fn strip(rows: u32, bytes_per_row: u32, x_offset: u32, y_offset: u32, written: u32) -> TW_IMAGEMEMXFER {
+		TW_IMAGEMEMXFER {
+			Compression: TWCP_NONE as TW_UINT16,
+			BytesPerRow: bytes_per_row,
+			Columns: bytes_per_row,
+			Rows: rows,
+			XOffset: x_offset,
+			YOffset: y_offset,
+			BytesWritten: written,
+			Memory: TW_MEMORY { Flags: 0, Length: 0, TheMem: ptr::null_mut() },
+		}
+	}
+
+	#[test]
+	fn memory_strips_are_bounds_checked() {
+		let buffer = [7u8; 8];
+		let mut image = Image::new(4, 2, PixelFormat::Gray8);
+
+		copy_strip(&mut image, &buffer, &strip(2, 4, 0, 0, 8), false).unwrap();
+		assert_eq!(vec![7u8; 8], image.data);
+
+		assert_eq!(Err(DSError::BadImage), copy_strip(&mut image, &buffer, &strip(1, 4, 5, 0, 8), false));
+		assert_eq!(Err(DSError::BadImage), copy_strip(&mut image, &buffer, &strip(3, 4, 0, 0, 8), false));
+		assert_eq!(Err(DSError::BadImage), copy_strip(&mut image, &buffer, &strip(2, 4, 0, 0, 6), false));
+		assert_eq!(Err(DSError::BadImage), copy_strip(&mut image, &buffer, &strip(1, 4, 0, 2, 4), false));
+		assert_eq!(Err(DSError::BadImage), copy_strip(&mut image, &buffer, &TW_IMAGEMEMXFER { Compression: TWCP_PACKBITS as TW_UINT16, ..strip(1, 4, 0, 0, 4) }, false));
+
+		// Unknown length, the image grows with the strips
+		let mut image = Image::new(4, 0, PixelFormat::Gray8);
+		copy_strip(&mut image, &buffer, &strip(1, 4, 0, 1, 4), true).unwrap();
+		assert_eq!(2, image.height);
+		assert_eq!([0, 0, 0, 0, 7, 7, 7, 7], image.data[..]);
+	}
+}
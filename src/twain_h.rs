@@ -2,4 +2,32 @@
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
 
+#[cfg(feature = "bindgen")]
 include!(concat!(env!("OUT_DIR"), "/twain_h_bindings.rs"));
+
+#[cfg(all(not(feature = "bindgen"), not(windows)))]
+include!("bindings/twain_h_unix.rs");
+
+#[cfg(all(not(feature = "bindgen"), windows))]
+include!("bindings/twain_h_windows.rs");
+
+#[cfg(all(test, feature = "bindgen"))]
+mod tests {
+	#[cfg(not(windows))]
+	const CHECKED_IN: &str = include_str!("bindings/twain_h_unix.rs");
+	#[cfg(windows)]
+	const CHECKED_IN: &str = include_str!("bindings/twain_h_windows.rs");
+
+	const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/twain_h_bindings.rs"));
+
+	#[test]
+	fn checked_in_bindings_match_generated() {
+		let first_difference = CHECKED_IN.lines().zip(GENERATED.lines())
+			.position(|(a, b)| a != b)
+			.unwrap_or_else(|| CHECKED_IN.lines().count().min(GENERATED.lines().count()));
+
+		assert!(CHECKED_IN == GENERATED,
+			"Checked-in bindings differ from generated ones at line {}, copy {}/twain_h_bindings.rs to src/bindings/",
+			first_difference + 1, env!("OUT_DIR"));
+	}
+}
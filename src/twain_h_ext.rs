@@ -6,6 +6,11 @@ pub type TwainUConst = u32;
 
 pub const STR32_LEN: usize = 34;
 pub const STR32_EMPTY: TW_STR32 = [0; STR32_LEN];
+pub const STR255_LEN: usize = 256;
+pub const STR255_EMPTY: TW_STR255 = [0; STR255_LEN];
+
+// twain.h writes this one as 0X0104, which bindgen doesn't pick up
+pub const MSG_DEVICEEVENT: TwainUConst = 0x0104;
 
 impl Default for TW_ENTRYPOINT {
 	fn default() -> Self {
@@ -43,19 +48,35 @@ impl Default for TW_IDENTITY {
 
 pub fn tw_str32<S: AsRef<str>>(string: S) -> TW_STR32 {
 	let mut twstr = STR32_EMPTY;
+	copy_to_tw_str(&mut twstr, string.as_ref());
+	twstr
+}
 
-	let slen = std::cmp::min(twstr.len() - 1, string.as_ref().len());
-	let sbytes = string.as_ref().as_bytes();
+pub fn tw_str32_to_string(twstr: &TW_STR32) -> String {
+	tw_str_to_string(twstr)
+}
+
+pub fn tw_str255<S: AsRef<str>>(string: S) -> TW_STR255 {
+	let mut twstr = STR255_EMPTY;
+	copy_to_tw_str(&mut twstr, string.as_ref());
+	twstr
+}
+
+pub fn tw_str255_to_string(twstr: &TW_STR255) -> String {
+	tw_str_to_string(twstr)
+}
+
+fn copy_to_tw_str(twstr: &mut [TW_INT8], string: &str) {
+	let slen = std::cmp::min(twstr.len() - 1, string.len());
+	let sbytes = string.as_bytes();
 
 	for i in 0..slen {
 		twstr[i] = sbytes[i] as i8;
 	}
 	twstr[slen] = 0;
-
-	twstr
 }
 
-pub fn tw_str32_to_string(twstr: &TW_STR32) -> String {
+fn tw_str_to_string(twstr: &[TW_INT8]) -> String {
 	let slen = twstr.len();
 	let mut sbytes = Vec::with_capacity(twstr.len());
 
@@ -106,6 +127,13 @@ mod tests {
 		assert_eq!(s[0..STR32_LEN-1], tw_str32_to_string(&twstr));
 	}
 
+	#[test]
+	fn long_string_to_str255_and_back() {
+		let s = "/tmp/".repeat(60);
+		let twstr = tw_str255(&s);
+		assert_eq!(s[0..STR255_LEN-1], tw_str255_to_string(&twstr));
+	}
+
 	#[test]
 	fn string_with_nul_to_str32_and_back() {
 		let s = String::from("Test\0string!");
@@ -1,6 +1,5 @@
 use twain2::*;
 use twain2::twain_h::*;
-use twain2::twain_h_ext::*;
 mod helper;
 
 use std::ptr;
@@ -57,16 +56,55 @@ fn test_openeddsm_new_and_get_data_sources() {
 	assert!(data_sources.is_ok());
 }
 
+#[test]
+fn test_openeddsm_find_sources() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	let wrapper = helper::get_dsm_entry_wrapper();
+
+	let identity = helper::get_app_identity(false);
+	let dsm = OpenedDSM::new(Arc::new(wrapper), identity).unwrap();
+	let data_sources = dsm.get_data_sources().unwrap();
+
+	for ds in &data_sources {
+		let found = dsm.find_source_by_name(&ds.product_name).unwrap();
+		assert_eq!(Some(ds.product_name.clone()), found.map(|f| f.product_name));
+
+		let by_manufacturer = dsm.find_by_manufacturer(&ds.manufacturer).unwrap();
+		assert!(by_manufacturer.iter().any(|f| f.id == ds.id));
+	}
+
+	assert!(dsm.find_source_by_name("No Such Source").unwrap().is_none());
+	assert!(dsm.find_sources(|_| false).unwrap().is_empty());
+}
+
+#[test]
+fn test_openeddsm_default_source() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	let wrapper = helper::get_dsm_entry_wrapper();
+
+	let identity = helper::get_app_identity(false);
+	let dsm = OpenedDSM::new(Arc::new(wrapper), identity).unwrap();
+
+	if let Some(ds) = dsm.get_data_sources().unwrap().into_iter().next() {
+		let previous = dsm.default_source().unwrap();
+
+		dsm.set_default_source(ds.identity).unwrap();
+		assert_eq!(ds.product_name, dsm.default_source().unwrap().product_name);
+
+		dsm.set_default_source(previous.identity).unwrap();
+	}
+}
+
 fn get_software_scanner(wrapper: DSMEntryWrapper) -> Option<(Arc<OpenedDSM>, Box<OpenedDS>)> {
 	let identity = helper::get_app_identity(true);
 	let dsm = OpenedDSM::new(Arc::new(wrapper), identity).unwrap();
-	for ds in dsm.get_data_sources().unwrap() {
-		if tw_str32_to_string(&ds.ProductName) == "TWAIN2 Software Scanner" {
-			let ds = dsm.open_data_source(ds).unwrap();
-			return Some((dsm, ds));
-		}
-	}
-	None
+	let ds = dsm.find_source_by_name("TWAIN2 Software Scanner").unwrap()?;
+	let ds = dsm.open_data_source(ds.identity).unwrap();
+	Some((dsm, ds))
 }
 
 #[test]
@@ -110,20 +148,152 @@ fn test_acquire_native_from_software_scanner_ds() {
 		assert_eq!(DSState::TransferReady, ds.get_state());
 
 		let mut calls = 0;
-		let res = ds.acquire_native_image(|h| {
+		let (res, outcome) = ds.acquire_native_image(|h| {
 			assert_ne!(0 as TW_HANDLE, h);
 			calls += 1;
 			42
-		});
-		assert_eq!(Ok(Some(42)), res);
+		}).unwrap();
+		assert_eq!(Some(42), res);
+		let outcome = outcome.unwrap();
+		assert!(!outcome.finished);
+		assert_eq!(Some(outcome), ds.last_transfer());
 
-		let res = ds.acquire_native_image(|h| {
+		let (res, _) = ds.acquire_native_image(|h| {
 			assert_ne!(0 as TW_HANDLE, h);
 			calls += 1;
 			42
-		});
-		assert_eq!(Ok(Some(42)), res);
+		}).unwrap();
+		assert_eq!(Some(42), res);
 
 		assert_eq!(2, calls);
 	}
 }
+
+#[test]
+fn test_capabilities_of_software_scanner_ds() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	if let Some((_dsm, ds)) = get_software_scanner(helper::get_dsm_entry_wrapper()) {
+		let caps = ds.supported_capabilities().unwrap();
+		assert!(caps.contains(&(ICAP_PIXELTYPE as TW_UINT16)));
+
+		let pixel_type = ds.get_capability(ICAP_PIXELTYPE).unwrap();
+		assert!(pixel_type.current().is_some());
+
+		let support = ds.query_capability_support(ICAP_PIXELTYPE).unwrap();
+		assert!(support.get());
+
+		ds.set_capability_value(ICAP_PIXELTYPE, capability::CapValue::UInt16(TWPT_GRAY as TW_UINT16)).unwrap();
+		let current = ds.get_capability_current(ICAP_PIXELTYPE).unwrap();
+		assert_eq!(Some(TWPT_GRAY), current.current().and_then(|v| v.as_u32()));
+	}
+}
+
+#[test]
+fn test_acquire_decoded_images_from_software_scanner_ds() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	if let Some((_dsm, ds)) = get_software_scanner(helper::get_dsm_entry_wrapper()) {
+		ds.set_transfer_mechanism(TWSX_MEMORY).unwrap();
+
+		let ui = TW_USERINTERFACE {
+			ShowUI: 0,
+			ModalUI: 0,
+			hParent: ptr::null_mut(),
+		};
+		ds.enable(ui).unwrap();
+
+		assert_eq!(DSState::TransferReady, ds.get_state());
+
+		let image = ds.acquire_memory_image().unwrap().unwrap();
+		assert!(image.width > 0 && image.height > 0);
+		assert_eq!(image.stride() * image.height as usize, image.data.len());
+	}
+}
+
+#[test]
+fn test_ext_image_info_from_software_scanner_ds() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	if let Some((_dsm, ds)) = get_software_scanner(helper::get_dsm_entry_wrapper()) {
+		let requested = [TWEI_DOCUMENTNUMBER as u16, TWEI_PAGENUMBER as u16];
+		let enabled = ds.enable_ext_image_info(&requested).unwrap();
+		assert!(enabled.iter().all(|id| requested.contains(id)));
+
+		let ui = TW_USERINTERFACE {
+			ShowUI: 0,
+			ModalUI: 0,
+			hParent: ptr::null_mut(),
+		};
+		ds.enable(ui).unwrap();
+
+		let image = ds.acquire_image().unwrap().unwrap();
+		assert!(image.ext_info.keys().all(|id| enabled.contains(id)));
+	}
+}
+
+#[test]
+fn test_stop_feeder_on_software_scanner_ds() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	if let Some((_dsm, ds)) = get_software_scanner(helper::get_dsm_entry_wrapper()) {
+		let ui = TW_USERINTERFACE {
+			ShowUI: 0,
+			ModalUI: 0,
+			hParent: ptr::null_mut(),
+		};
+		ds.enable(ui).unwrap();
+
+		match ds.stop_feeder().unwrap() {
+			Some(outcome) if outcome.finished => assert_eq!(DSState::SourceEnabled, ds.get_state()),
+			_ => assert_eq!(DSState::TransferReady, ds.get_state()),
+		}
+
+		while ds.get_state() == DSState::TransferReady {
+			ds.acquire_image().unwrap();
+		}
+		assert_eq!(DSState::SourceEnabled, ds.get_state());
+	}
+}
+
+#[test]
+fn test_feeder_config_on_software_scanner_ds() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	if let Some((_dsm, ds)) = get_software_scanner(helper::get_dsm_entry_wrapper()) {
+		let config = feeder::FeederConfig { require_paper: false, ..Default::default() };
+		let settings = ds.apply_feeder_config(&config).unwrap();
+		assert!(settings.feeder_enabled);
+		assert!(!settings.duplex_enabled);
+	}
+}
+
+#[test]
+fn test_acquire_with_recovery_from_software_scanner_ds() {
+	helper::init();
+	let _twain_mutex = TWAIN_MUTEX.lock();
+
+	if let Some((_dsm, ds)) = get_software_scanner(helper::get_dsm_entry_wrapper()) {
+		let ui = TW_USERINTERFACE {
+			ShowUI: 0,
+			ModalUI: 0,
+			hParent: ptr::null_mut(),
+		};
+		ds.enable(ui).unwrap();
+		ds.wait_while_state(DSState::SourceEnabled, std::time::Duration::from_secs(5));
+
+		let options = recovery::RecoveryOptions::default();
+		let batch = ds.acquire_with_recovery(&options, |ds| ds.acquire_image(), |_| recovery::RecoveryAction::Abort);
+
+		assert!(!batch.pages.is_empty());
+		assert!(batch.faults.is_empty());
+		assert!(!batch.aborted);
+		assert!(batch.error.is_none());
+		assert_eq!(DSState::SourceEnabled, ds.get_state());
+	}
+}